@@ -24,6 +24,7 @@ mod edwards_benches {
     use criterion::Throughput;
     use super::*;
 
+    use curve25519_dalek::edwards::{EdwardsBasepointTable, EdwardsCombTable};
     use curve25519_dalek::edwards::{EdwardsBasepointTableRadix128, EdwardsBasepointTableRadix16, EdwardsBasepointTableRadix256, EdwardsBasepointTableRadix32, EdwardsBasepointTableRadix64, EdwardsPoint};
     use curve25519_dalek::traits::BasepointTable;
 
@@ -39,6 +40,13 @@ mod edwards_benches {
         });
     }
 
+    fn decompress_vartime(c: &mut Criterion) {
+        let B_comp = &constants::ED25519_BASEPOINT_COMPRESSED;
+        c.bench_function("EdwardsPoint variable-time decompression", move |b| {
+            b.iter(|| B_comp.decompress_vartime().unwrap())
+        });
+    }
+
     fn consttime_fixed_base_scalar_mul_varradix<M: Measurement>(g: &mut BenchmarkGroup<M>) {
         let P = &constants::ED25519_BASEPOINT_POINT;
         let mut rng = thread_rng();
@@ -80,6 +88,21 @@ mod edwards_benches {
         });
     }
 
+    fn consttime_fixed_base_scalar_mul_comb<M: Measurement>(g: &mut BenchmarkGroup<M>) {
+        let P = &constants::ED25519_BASEPOINT_POINT;
+
+        for &w in [4, 5, 6, 7, 8].iter() {
+            let table = EdwardsCombTable::create(&P, w);
+            let mut rng = thread_rng();
+
+            g.throughput(Throughput::Elements(1));
+            g.bench_function(format!("Constant-time fixed-base scalar mul (comb, {} bits)", w), move |b| {
+                let s = Scalar::random(&mut rng);
+                b.iter(|| &table * &s)
+            });
+        }
+    }
+
     fn consttime_fixed_base_scalar_mul<M: Measurement>(g: &mut BenchmarkGroup<M>) {
         let B = &constants::ED25519_BASEPOINT_TABLE;
         let mut rng = thread_rng();
@@ -113,12 +136,130 @@ mod edwards_benches {
         });
     }
 
+    fn vartime_small_multiscalar_mul(c: &mut Criterion) {
+        use curve25519_dalek::traits::VartimeMultiscalarMul;
+
+        let mut group: BenchmarkGroup<_> = c.benchmark_group("Variable-time 2- and 3-term multiscalar mul");
+        let mut rng = thread_rng();
+        let s: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut rng)).collect();
+        let P: Vec<EdwardsPoint> = (0..3)
+            .map(|_| &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE)
+            .collect();
+
+        group.bench_function("vartime_multiscalar_mul_2", |b| {
+            b.iter(|| EdwardsPoint::vartime_multiscalar_mul_2(&s[0], &P[0], &s[1], &P[1]))
+        });
+        group.bench_function("Generic, 2 terms", |b| {
+            b.iter(|| EdwardsPoint::vartime_multiscalar_mul(&s[..2], &P[..2]))
+        });
+        group.bench_function("vartime_multiscalar_mul_3", |b| {
+            b.iter(|| EdwardsPoint::vartime_multiscalar_mul_3(&s[0], &P[0], &s[1], &P[1], &s[2], &P[2]))
+        });
+        group.bench_function("Generic, 3 terms", |b| {
+            b.iter(|| EdwardsPoint::vartime_multiscalar_mul(&s, &P))
+        });
+
+        group.finish();
+    }
+
+    fn sub_scalar_mul(c: &mut Criterion) {
+        let mut group: BenchmarkGroup<_> = c.benchmark_group("A - cB");
+        let mut rng = thread_rng();
+        let A = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+        let B = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+        let s = Scalar::random(&mut rng);
+
+        group.bench_function("sub_scalar_mul", |b| b.iter(|| A.sub_scalar_mul(&s, &B)));
+        group.bench_function("Two-step multiply and subtract", |b| b.iter(|| A - s * B));
+
+        group.finish();
+    }
+
+    fn add_sub(c: &mut Criterion) {
+        let mut group: BenchmarkGroup<_> = c.benchmark_group("P + Q and P - Q");
+        let mut rng = thread_rng();
+        let P = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+        let Q = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+
+        group.bench_function("add_sub", |b| b.iter(|| P.add_sub(&Q)));
+        group.bench_function("Separate addition and subtraction", |b| b.iter(|| (P + Q, P - Q)));
+
+        group.finish();
+    }
+
+    fn grouped_multiscalar(c: &mut Criterion) {
+        use curve25519_dalek::traits::VartimeMultiscalarMul;
+
+        let mut group: BenchmarkGroup<_> = c.benchmark_group("Grouped multiscalar multiplication, 64 groups of 4");
+        let mut rng = thread_rng();
+        let pairs: Vec<(Scalar, EdwardsPoint)> = (0..256)
+            .map(|_| {
+                let P = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+                (Scalar::random(&mut rng), P)
+            })
+            .collect();
+        let groups: Vec<&[(Scalar, EdwardsPoint)]> = pairs.chunks(4).collect();
+
+        group.bench_function("vartime_grouped_multiscalar", |b| {
+            b.iter(|| EdwardsPoint::vartime_grouped_multiscalar(&groups))
+        });
+        group.bench_function("vartime_multiscalar_mul per group", |b| {
+            b.iter(|| {
+                groups
+                    .iter()
+                    .map(|g| {
+                        EdwardsPoint::vartime_multiscalar_mul(
+                            g.iter().map(|(a, _)| a),
+                            g.iter().map(|(_, P)| P),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+
+        group.finish();
+    }
+
+    fn double_batch(c: &mut Criterion) {
+        let mut group: BenchmarkGroup<_> = c.benchmark_group("Batched point doubling");
+        let mut rng = thread_rng();
+        let points: Vec<EdwardsPoint> = (0..64)
+            .map(|_| &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE)
+            .collect();
+
+        group.bench_function("double_batch", |b| b.iter(|| EdwardsPoint::double_batch(&points)));
+        group.bench_function("Naive loop", |b| {
+            b.iter(|| points.iter().map(|P| P + P).collect::<Vec<_>>())
+        });
+
+        group.finish();
+    }
+
+    fn diff_mul(c: &mut Criterion) {
+        let mut group: BenchmarkGroup<_> = c.benchmark_group("Table multiplication difference aB - bC");
+        let mut rng = thread_rng();
+        let B = &constants::ED25519_BASEPOINT_TABLE;
+        let C = EdwardsBasepointTable::create(&EdwardsPoint::random(&mut rng));
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+
+        group.bench_function("EdwardsBasepointTable::diff_mul", |bench| {
+            bench.iter(|| B.diff_mul(&a, &C, &b))
+        });
+        group.bench_function("Two EdwardsBasepointTable multiplications", |bench| {
+            bench.iter(|| B * &a - &C * &b)
+        });
+
+        group.finish();
+    }
+
     fn single_scalar_multiplications(c: &mut Criterion) {
         let mut group: BenchmarkGroup<_> = c.benchmark_group("Scalar multiplications");
 
         consttime_fixed_base_scalar_mul(&mut group);
         consttime_variable_base_scalar_mul(&mut group);
         consttime_fixed_base_scalar_mul_varradix(&mut group);
+        consttime_fixed_base_scalar_mul_comb(&mut group);
 
         group.finish();
     }
@@ -129,13 +270,21 @@ mod edwards_benches {
         targets =
         compress,
         decompress,
+        decompress_vartime,
         single_scalar_multiplications,
         vartime_double_base_scalar_mul,
+        vartime_small_multiscalar_mul,
+        sub_scalar_mul,
+        add_sub,
+        double_batch,
+        grouped_multiscalar,
+        diff_mul,
     }
 }
 
 mod multiscalar_benches {
     use criterion::Throughput;
+    use rand::RngCore;
     use super::*;
 
     use curve25519_dalek::edwards::EdwardsPoint;
@@ -236,6 +385,39 @@ mod multiscalar_benches {
         }
     }
 
+    fn construct_short_scalars(n: usize) -> Vec<Scalar> {
+        let mut rng = thread_rng();
+        (0..n)
+            .map(|_| {
+                let mut bytes = [0u8; 32];
+                rng.fill_bytes(&mut bytes[..16]);
+                Scalar::from_bits(bytes)
+            })
+            .collect()
+    }
+
+    fn vartime_precomputed_short_scalars<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        for multiscalar_size in &MULTISCALAR_SIZES {
+            c.throughput(Throughput::Elements(*multiscalar_size as u64));
+            c.bench_with_input(
+                BenchmarkId::new(
+                    "Variable-time fixed-base multiscalar multiplication with 128-bit scalars",
+                    &multiscalar_size,
+                ),
+                &multiscalar_size,
+                move |b, &&total_size| {
+                    let static_points = construct_points(total_size);
+                    let precomp = VartimeEdwardsPrecomputation::new(&static_points);
+                    b.iter_batched(
+                        || construct_short_scalars(total_size),
+                        |scalars| precomp.vartime_multiscalar_mul(&scalars),
+                        BatchSize::SmallInput,
+                    );
+                },
+            );
+        }
+    }
+
     fn vartime_precomputed_helper<M: Measurement>(
         c: &mut BenchmarkGroup<M>,
         dynamic_fraction: f64,
@@ -282,12 +464,42 @@ mod multiscalar_benches {
         }
     }
 
+    fn pippenger_window_override<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        // Many points with 64-bit scalars: the default heuristic picks an
+        // 8-bit window, whose bucket additions dominate for short scalars.
+        let size = 1024;
+        let points = construct_points(size);
+        let mut rng = thread_rng();
+        let scalars: Vec<Scalar> = (0..size).map(|_| Scalar::from(rng.next_u64())).collect();
+
+        c.bench_function("Pippenger with 64-bit scalars, default window", |b| {
+            b.iter(|| EdwardsPoint::vartime_multiscalar_mul(&scalars, &points))
+        });
+        for w in [5, 6].iter() {
+            c.bench_with_input(
+                BenchmarkId::new("Pippenger with 64-bit scalars, window override", w),
+                w,
+                |b, &w| {
+                    b.iter(|| {
+                        EdwardsPoint::optional_multiscalar_mul_with_window(
+                            &scalars,
+                            points.iter().map(|P| Some(*P)),
+                            w,
+                        )
+                    })
+                },
+            );
+        }
+    }
+
     fn multiscalar_multiplications(c: &mut Criterion) {
         let mut group: BenchmarkGroup<_> = c.benchmark_group("Multiscalar multiplications");
 
         consttime_multiscalar_mul(&mut group);
         vartime_multiscalar_mul(&mut group);
         vartime_precomputed_pure_static(&mut group);
+        vartime_precomputed_short_scalars(&mut group);
+        pippenger_window_override(&mut group);
 
         let dynamic_fracs = [0.0, 0.2, 0.5];
 
@@ -310,7 +522,7 @@ mod multiscalar_benches {
 mod ristretto_benches {
     use criterion::Throughput;
     use super::*;
-    use curve25519_dalek::ristretto::RistrettoPoint;
+    use curve25519_dalek::ristretto::{RistrettoBasepointTable, RistrettoCommitmentTable, RistrettoPoint};
 
     fn compress(c: &mut Criterion) {
         let mut group: BenchmarkGroup<_> = c.benchmark_group("compression");
@@ -358,6 +570,27 @@ mod ristretto_benches {
         group.finish();
     }
 
+    fn commitment_table(c: &mut Criterion) {
+        let mut group: BenchmarkGroup<_> = c.benchmark_group("Pedersen commitment vG + bH");
+        let mut rng = OsRng;
+        let G = constants::RISTRETTO_BASEPOINT_POINT;
+        let H = RistrettoPoint::random(&mut rng);
+        let table = RistrettoCommitmentTable::new(&G, &H);
+        let G_table = RistrettoBasepointTable::create(&G);
+        let H_table = RistrettoBasepointTable::create(&H);
+        let v = Scalar::random(&mut rng);
+        let blinding = Scalar::random(&mut rng);
+
+        group.bench_function("RistrettoCommitmentTable::commit", |b| {
+            b.iter(|| table.commit(&v, &blinding))
+        });
+        group.bench_function("Two RistrettoBasepointTable multiplications", |b| {
+            b.iter(|| &v * &G_table + &blinding * &H_table)
+        });
+
+        group.finish();
+    }
+
     criterion_group! {
         name = ristretto_benches;
         config = Criterion::default();
@@ -365,6 +598,7 @@ mod ristretto_benches {
         compress,
         decompress,
         double_and_compress_group,
+        commitment_table,
     }
 }
 
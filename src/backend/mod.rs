@@ -46,6 +46,13 @@ compile_error!(
      please enable one of: u32_backend, u64_backend, fiat_u32_backend, fiat_u64_backend, simd_backend"
 );
 
+#[cfg(all(
+    feature = "stats",
+    feature = "simd_backend",
+    any(target_feature = "avx2", target_feature = "avx512ifma")
+))]
+compile_error!("the stats feature is not supported by the simd_backend");
+
 pub mod serial;
 
 #[cfg(any(
@@ -63,3 +70,67 @@ pub mod serial;
     ))))
 )]
 pub mod vector;
+
+/// A heap buffer of NAF digits of (possibly secret) scalars, which is
+/// zeroized when dropped.
+///
+/// This only reduces the scalar-derived residue left in memory: the
+/// algorithms using it are still variable-time.
+#[cfg(feature = "alloc")]
+pub(crate) struct ZeroizingNafs(pub(crate) ::prelude::Vec<[i8; 256]>);
+
+#[cfg(feature = "alloc")]
+impl ::core::ops::Deref for ZeroizingNafs {
+    type Target = [[i8; 256]];
+
+    fn deref(&self) -> &[[i8; 256]] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for ZeroizingNafs {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        for naf in self.0.iter_mut() {
+            naf[..].zeroize();
+        }
+    }
+}
+
+/// Compute the width-\\(5\\) non-adjacent form of a signed 64-bit
+/// integer, which has at most 65 digits.
+#[cfg(feature = "alloc")]
+pub(crate) fn small_non_adjacent_form(c: i64) -> [i8; 65] {
+    let mut naf = [0i8; 65];
+
+    // Work with the magnitude, which may be 2^63, and fix up signs at
+    // the end.
+    let mut k = c.wrapping_abs() as u64 as u128;
+    let mut i = 0;
+    while k != 0 {
+        if k & 1 == 1 {
+            let mut digit = (k & 31) as i8;
+            if digit >= 16 {
+                digit -= 32;
+            }
+            naf[i] = digit;
+            if digit > 0 {
+                k -= digit as u128;
+            } else {
+                k += (-digit) as u128;
+            }
+        }
+        k >>= 1;
+        i += 1;
+    }
+
+    if c < 0 {
+        for digit in naf.iter_mut() {
+            *digit = -*digit;
+        }
+    }
+
+    naf
+}
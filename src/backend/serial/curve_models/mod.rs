@@ -177,7 +177,11 @@ pub struct CompletedPoint {
 /// More details on the relationships between the different curve models
 /// can be found in the module-level documentation.
 // Safe to derive Eq because affine coordinates.
+//
+// With the `ffi` feature, the layout is fixed for
+// `EdwardsBasepointTable::as_flat_field_limbs`.
 #[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "ffi", repr(C))]
 #[allow(missing_docs)]
 pub struct AffineNielsPoint {
     pub y_plus_x:  FieldElement,
@@ -445,6 +449,43 @@ impl<'a, 'b> Sub<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
     }
 }
 
+impl EdwardsPoint {
+    /// Compute both `self + other` and `self - other`, sharing the
+    /// products which do not depend on the sign of `other`.
+    ///
+    /// This costs 6 multiplications, rather than the 8 needed for a
+    /// separate addition and subtraction.
+    pub(crate) fn add_sub_projective_niels(
+        &self,
+        other: &ProjectiveNielsPoint,
+    ) -> (CompletedPoint, CompletedPoint) {
+        let Y_plus_X  = &self.Y + &self.X;
+        let Y_minus_X = &self.Y - &self.X;
+        let PP = &Y_plus_X  * &other.Y_plus_X;
+        let MM = &Y_minus_X * &other.Y_minus_X;
+        let PM = &Y_plus_X  * &other.Y_minus_X;
+        let MP = &Y_minus_X * &other.Y_plus_X;
+        let TT2d = &self.T * &other.T2d;
+        let ZZ   = &self.Z * &other.Z;
+        let ZZ2  = &ZZ + &ZZ;
+
+        let sum = CompletedPoint{
+            X: &PP - &MM,
+            Y: &PP + &MM,
+            Z: &ZZ2 + &TT2d,
+            T: &ZZ2 - &TT2d
+        };
+        let difference = CompletedPoint{
+            X: &PM - &MP,
+            Y: &PM + &MP,
+            Z: &ZZ2 - &TT2d,
+            T: &ZZ2 + &TT2d
+        };
+
+        (sum, difference)
+    }
+}
+
 //#[doc(hidden)]
 impl<'a, 'b> Add<&'b AffineNielsPoint> for &'a EdwardsPoint {
     type Output = CompletedPoint;
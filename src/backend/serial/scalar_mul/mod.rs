@@ -21,11 +21,55 @@ pub mod variable_base;
 
 pub mod vartime_double_base;
 
+pub mod inline_straus;
+
 #[cfg(feature = "alloc")]
 pub mod straus;
 
 #[cfg(feature = "alloc")]
 pub mod precomputed_straus;
 
+#[cfg(feature = "const_generics")]
+pub mod precomputed_straus_array;
+
 #[cfg(feature = "alloc")]
 pub mod pippenger;
+
+/// Counts the group operations of a multiscalar multiplication.
+///
+/// With the `stats` feature disabled this is a zero-sized type whose
+/// methods do nothing, so the instrumented code compiles to the same
+/// thing as uninstrumented code.
+#[derive(Default)]
+pub(crate) struct StatsRecorder {
+    #[cfg(feature = "stats")]
+    pub(crate) stats: ::edwards::MultiscalarStats,
+}
+
+#[allow(unused_variables)]
+impl StatsRecorder {
+    #[inline(always)]
+    pub(crate) fn doublings(&mut self, n: usize) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.doublings += n;
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn additions(&mut self, n: usize) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.additions += n;
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub(crate) fn window(&mut self, w: usize) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.window = w;
+        }
+    }
+}
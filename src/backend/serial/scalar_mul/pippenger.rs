@@ -13,6 +13,7 @@
 
 use core::borrow::Borrow;
 
+use backend::serial::scalar_mul::StatsRecorder;
 use edwards::EdwardsPoint;
 use scalar::Scalar;
 use traits::VartimeMultiscalarMul;
@@ -71,8 +72,48 @@ impl VartimeMultiscalarMul for Pippenger {
         I::Item: Borrow<Scalar>,
         J: IntoIterator<Item = Option<EdwardsPoint>>,
     {
-        use traits::Identity;
+        Pippenger::optional_multiscalar_mul_recorded(scalars, points, &mut StatsRecorder::default())
+    }
+}
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl Pippenger {
+    /// Like `optional_multiscalar_mul`, but with a digit width of `w`
+    /// bits, rather than one chosen from the number of inputs.
+    ///
+    /// # Panics
+    ///
+    /// If `w` is not between 4 and 8, inclusive.
+    pub fn optional_multiscalar_mul_with_window<I, J>(
+        scalars: I,
+        points: J,
+        w: usize,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        Pippenger::optional_multiscalar_mul_with_window_recorded(
+            scalars,
+            points,
+            w,
+            &mut StatsRecorder::default(),
+        )
+    }
+
+    /// Like `VartimeMultiscalarMul::optional_multiscalar_mul`, counting
+    /// the group operations in `stats`.
+    pub(crate) fn optional_multiscalar_mul_recorded<I, J>(
+        scalars: I,
+        points: J,
+        stats: &mut StatsRecorder,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
         let mut scalars = scalars.into_iter();
         let size = scalars.by_ref().size_hint().0;
 
@@ -87,6 +128,25 @@ impl VartimeMultiscalarMul for Pippenger {
             8
         };
 
+        Pippenger::optional_multiscalar_mul_with_window_recorded(scalars, points, w, stats)
+    }
+
+    fn optional_multiscalar_mul_with_window_recorded<I, J>(
+        scalars: I,
+        points: J,
+        w: usize,
+        stats: &mut StatsRecorder,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        use traits::Identity;
+
+        assert!((4..=8).contains(&w), "Pippenger window must be between 4 and 8 bits");
+        stats.window(w);
+
         let max_digit: usize = 1 << w;
         let digits_count: usize = Scalar::to_radix_2w_size_hint(w);
         let buckets_count: usize = max_digit / 2; // digits are signed+centered hence 2^w/2, excluding 0-th bucket
@@ -94,6 +154,7 @@ impl VartimeMultiscalarMul for Pippenger {
         // Collect optimized scalars and points in buffers for repeated access
         // (scanning the whole set per digit position).
         let scalars = scalars
+            .into_iter()
             .map(|s| s.borrow().to_radix_2w(w));
 
         let points = points
@@ -127,9 +188,11 @@ impl VartimeMultiscalarMul for Pippenger {
                 if digit > 0 {
                     let b = (digit - 1) as usize;
                     buckets[b] = (&buckets[b] + pt).to_extended();
+                    stats.additions(1);
                 } else if digit < 0 {
                     let b = (-digit - 1) as usize;
                     buckets[b] = (&buckets[b] - pt).to_extended();
+                    stats.additions(1);
                 }
             }
 
@@ -147,6 +210,7 @@ impl VartimeMultiscalarMul for Pippenger {
                 buckets_intermediate_sum += buckets[i];
                 buckets_sum += buckets_intermediate_sum;
             }
+            stats.additions(2 * (buckets_count - 1));
 
             buckets_sum
         });
@@ -155,10 +219,11 @@ impl VartimeMultiscalarMul for Pippenger {
         // `unwrap()` always succeeds because we know we have more than zero digits.
         let hi_column = columns.next().unwrap();
 
-        Some(
-            columns
-                .fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p),
-        )
+        let result = columns.fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p);
+        stats.doublings(w * (digits_count - 1));
+        stats.additions(digits_count - 1);
+
+        Some(result)
     }
 }
 
@@ -16,8 +16,11 @@ use core::borrow::Borrow;
 use backend::serial::curve_models::{
     AffineNielsPoint, CompletedPoint, ProjectiveNielsPoint, ProjectivePoint,
 };
+use backend::ZeroizingNafs;
 use edwards::EdwardsPoint;
 use scalar::Scalar;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroizing;
 use traits::{Identity, VartimePrecomputedMultiscalarMul, VartimePrecomputedSubsetMultiscalarMul};
 use window::{NafLookupTable5, NafLookupTable8};
 
@@ -28,6 +31,55 @@ pub struct VartimePrecomputedStraus {
     static_lookup_tables: Vec<NafLookupTable8<AffineNielsPoint>>,
 }
 
+impl VartimePrecomputedStraus {
+    pub fn len(&self) -> usize {
+        self.static_lookup_tables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.static_lookup_tables.is_empty()
+    }
+
+    /// The number of heap bytes used by the lookup tables for
+    /// `num_points` static points.
+    pub fn footprint_bytes(num_points: usize) -> usize {
+        num_points * ::core::mem::size_of::<NafLookupTable8<AffineNielsPoint>>()
+    }
+
+    /// The number of heap bytes allocated for the lookup tables, which
+    /// may exceed `footprint_bytes(self.len())` after an `append`.
+    pub fn footprint(&self) -> usize {
+        Self::footprint_bytes(self.static_lookup_tables.capacity())
+    }
+
+    /// Move the lookup tables of `other` onto the end of `self`.
+    pub fn append(&mut self, mut other: Self) {
+        self.static_lookup_tables.append(&mut other.static_lookup_tables);
+    }
+
+    /// Concatenate precomputations, in order, without rebuilding their
+    /// lookup tables.
+    pub fn concat<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        Self {
+            static_lookup_tables: parts
+                .into_iter()
+                .flat_map(|part| part.static_lookup_tables)
+                .collect(),
+        }
+    }
+
+    /// Reconstruct the static points from the first entry, \\(1B\_i\\),
+    /// of each lookup table.
+    pub fn points<'a>(&'a self) -> impl Iterator<Item = EdwardsPoint> + 'a {
+        self.static_lookup_tables
+            .iter()
+            .map(|table| (&EdwardsPoint::identity() + &table.select(1)).to_extended())
+    }
+}
+
 impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
     type Point = EdwardsPoint;
 
@@ -57,15 +109,34 @@ impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
         J::Item: Borrow<Scalar>,
         K: IntoIterator<Item = Option<Self::Point>>,
     {
-        let static_nafs = static_scalars
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect::<Vec<_>>();
-        let dynamic_nafs: Vec<_> = dynamic_scalars
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect::<Vec<_>>();
+        // The NAFs are derived from the scalars, so erase them once
+        // we are done with them.
+        let static_nafs = ZeroizingNafs(
+            static_scalars
+                .into_iter()
+                .map(|c| c.borrow().non_adjacent_form(5))
+                .collect(),
+        );
+        let dynamic_nafs = ZeroizingNafs(
+            dynamic_scalars
+                .into_iter()
+                .map(|c| c.borrow().non_adjacent_form(5))
+                .collect(),
+        );
 
+        // The tables for the dynamic points are independent of each
+        // other, so they can be built in parallel.
+        #[cfg(feature = "parallel")]
+        let dynamic_lookup_tables = {
+            use rayon::prelude::*;
+
+            let dynamic_points: Vec<Option<EdwardsPoint>> = dynamic_points.into_iter().collect();
+            dynamic_points
+                .par_iter()
+                .map(|P_opt| P_opt.as_ref().map(NafLookupTable5::<ProjectiveNielsPoint>::from))
+                .collect::<Option<Vec<_>>>()?
+        };
+        #[cfg(not(feature = "parallel"))]
         let dynamic_lookup_tables = dynamic_points
             .into_iter()
             .map(|P_opt| P_opt.map(|P| NafLookupTable5::<ProjectiveNielsPoint>::from(&P)))
@@ -76,11 +147,20 @@ impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
         assert_eq!(sp, static_nafs.len());
         assert_eq!(dp, dynamic_nafs.len());
 
-        // We could save some doublings by looking for the highest
-        // nonzero NAF coefficient, but since we might have a lot of
-        // them to search, it's not clear it's worthwhile to check.
-        let mut S = ProjectivePoint::identity();
+        // Skip the leading rows where every NAF coefficient is zero.
+        // Scanning a row costs no more than processing it in the main
+        // loop, minus its doubling, so this is never much slower, and
+        // saves many doublings when all the scalars are short.
+        let mut max_j: usize = 255;
         for j in (0..256).rev() {
+            max_j = j;
+            if dynamic_nafs.iter().chain(static_nafs.iter()).any(|naf| naf[j] != 0) {
+                break;
+            }
+        }
+
+        let mut S = ProjectivePoint::identity();
+        for j in (0..=max_j).rev() {
             let mut R: CompletedPoint = S.double();
 
             for i in 0..dp {
@@ -140,10 +220,12 @@ impl VartimePrecomputedSubsetMultiscalarMul for VartimePrecomputedSubsetStraus {
         let (static_scalars_pos , static_scalars_vals) : (Vec<usize>, Vec<S>) = static_scalars.into_iter().unzip();
         let num_scalars = static_scalars_pos.len();
 
-        let static_nafs = static_scalars_vals
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect::<Vec<_>>();
+        let static_nafs = ZeroizingNafs(
+            static_scalars_vals
+                .into_iter()
+                .map(|c| c.borrow().non_adjacent_form(5))
+                .collect(),
+        );
         // let dynamic_nafs: Vec<_> = dynamic_scalars
         //     .into_iter()
         //     .map(|c| c.borrow().non_adjacent_form(5))
@@ -159,11 +241,18 @@ impl VartimePrecomputedSubsetMultiscalarMul for VartimePrecomputedSubsetStraus {
         assert!(num_scalars <= sp);
         //assert_eq!(dp, dynamic_nafs.len());
 
-        // We could save some doublings by looking for the highest
-        // nonzero NAF coefficient, but since we might have a lot of
-        // them to search, it's not clear it's worthwhile to check.
-        let mut S = ProjectivePoint::identity();
+        // Skip the leading rows where every NAF coefficient is zero,
+        // as in `optional_mixed_multiscalar_mul`.
+        let mut max_j: usize = 255;
         for j in (0..256).rev() {
+            max_j = j;
+            if static_nafs.iter().any(|naf| naf[j] != 0) {
+                break;
+            }
+        }
+
+        let mut S = ProjectivePoint::identity();
+        for j in (0..=max_j).rev() {
             let mut R: CompletedPoint = S.double();
 
             // for i in 0..dp {
@@ -191,3 +280,67 @@ impl VartimePrecomputedSubsetMultiscalarMul for VartimePrecomputedSubsetStraus {
         S.to_extended()
     }
 }
+
+impl VartimePrecomputedSubsetStraus {
+    /// Like `vartime_subset_multiscalar_mul`, but with a memory access
+    /// pattern independent of which static points are selected.
+    ///
+    /// For every nonzero NAF digit, each static lookup table is read and
+    /// the contribution of the selected one is chosen with a conditional
+    /// select.  The running time still depends on the scalar values.
+    pub fn subset_multiscalar_mul_ct<I, S>(&self, static_scalars: I) -> EdwardsPoint
+    where
+        I: IntoIterator<Item = (usize, S)>,
+        S: Borrow<Scalar>,
+    {
+        let sp = self.static_lookup_tables.len();
+
+        let (static_scalars_pos, static_nafs): (Vec<u64>, Vec<[i8; 256]>) = static_scalars
+            .into_iter()
+            .map(|(pos, c)| (pos as u64, c.borrow().non_adjacent_form(5)))
+            .unzip();
+        let static_scalars_pos = Zeroizing::new(static_scalars_pos);
+        let static_nafs = ZeroizingNafs(static_nafs);
+        assert!(static_scalars_pos.len() <= sp);
+
+        // Check that every position refers to a static point, without
+        // branching on the positions themselves.
+        let mut positions_in_range = Choice::from(1u8);
+        for pos in static_scalars_pos.iter() {
+            let mut in_range = Choice::from(0u8);
+            for k in 0..sp {
+                in_range |= (k as u64).ct_eq(pos);
+            }
+            positions_in_range &= in_range;
+        }
+        assert!(bool::from(positions_in_range));
+
+        let mut S = ProjectivePoint::identity();
+        for j in (0..256).rev() {
+            let mut R: CompletedPoint = S.double();
+
+            for (pos, naf) in static_scalars_pos.iter().zip(static_nafs.iter()) {
+                let t_ij = naf[j];
+                if t_ij == 0 {
+                    continue;
+                }
+
+                let abs_t_ij = if t_ij > 0 { t_ij as usize } else { (-t_ij) as usize };
+                let mut P = AffineNielsPoint::identity();
+                for (k, table) in self.static_lookup_tables.iter().enumerate() {
+                    P.conditional_assign(&table.select(abs_t_ij), (k as u64).ct_eq(pos));
+                }
+
+                if t_ij > 0 {
+                    R = &R.to_extended() + &P;
+                } else {
+                    R = &R.to_extended() - &P;
+                }
+            }
+
+            S = R.to_projective();
+        }
+
+        S.to_extended()
+    }
+}
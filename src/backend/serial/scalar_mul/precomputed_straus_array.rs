@@ -0,0 +1,99 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Precomputation for Straus's method over a fixed number of static
+//! points, stored inline without allocation.
+
+#![allow(non_snake_case)]
+
+use backend::serial::curve_models::{
+    AffineNielsPoint, CompletedPoint, ProjectiveNielsPoint, ProjectivePoint,
+};
+use edwards::EdwardsPoint;
+use scalar::Scalar;
+use traits::Identity;
+use window::{NafLookupTable5, NafLookupTable8};
+use zeroize::Zeroize;
+
+pub struct VartimePrecomputedStrausArray<const N: usize> {
+    static_lookup_tables: [NafLookupTable8<AffineNielsPoint>; N],
+}
+
+impl<const N: usize> VartimePrecomputedStrausArray<N> {
+    pub fn new(static_points: &[EdwardsPoint; N]) -> Self {
+        let identity_table = NafLookupTable8::<AffineNielsPoint>::from(&EdwardsPoint::identity());
+        let mut static_lookup_tables = [identity_table; N];
+
+        for (table, P) in static_lookup_tables.iter_mut().zip(static_points.iter()) {
+            *table = NafLookupTable8::<AffineNielsPoint>::from(P);
+        }
+
+        Self { static_lookup_tables }
+    }
+
+    pub fn vartime_mixed_multiscalar_mul<const M: usize>(
+        &self,
+        static_scalars: &[Scalar; N],
+        dynamic_scalars: &[Scalar; M],
+        dynamic_points: &[EdwardsPoint; M],
+    ) -> EdwardsPoint {
+        let mut static_nafs = [[0i8; 256]; N];
+        for (naf, s) in static_nafs.iter_mut().zip(static_scalars.iter()) {
+            *naf = s.non_adjacent_form(8);
+        }
+
+        let mut dynamic_nafs = [[0i8; 256]; M];
+        for (naf, s) in dynamic_nafs.iter_mut().zip(dynamic_scalars.iter()) {
+            *naf = s.non_adjacent_form(5);
+        }
+
+        let identity_table =
+            NafLookupTable5::<ProjectiveNielsPoint>::from(&EdwardsPoint::identity());
+        let mut dynamic_lookup_tables = [identity_table; M];
+        for (table, P) in dynamic_lookup_tables.iter_mut().zip(dynamic_points.iter()) {
+            *table = NafLookupTable5::<ProjectiveNielsPoint>::from(P);
+        }
+
+        // Skip the leading rows where every NAF coefficient is zero.
+        let mut max_j: usize = 255;
+        for j in (0..256).rev() {
+            max_j = j;
+            if static_nafs.iter().chain(dynamic_nafs.iter()).any(|naf| naf[j] != 0) {
+                break;
+            }
+        }
+
+        let mut S = ProjectivePoint::identity();
+        for j in (0..=max_j).rev() {
+            let mut R: CompletedPoint = S.double();
+
+            for (naf, table) in dynamic_nafs.iter().zip(dynamic_lookup_tables.iter()) {
+                let t_ij = naf[j];
+                if t_ij > 0 {
+                    R = &R.to_extended() + &table.select(t_ij as usize);
+                } else if t_ij < 0 {
+                    R = &R.to_extended() - &table.select(-t_ij as usize);
+                }
+            }
+
+            for (naf, table) in static_nafs.iter().zip(self.static_lookup_tables.iter()) {
+                let t_ij = naf[j];
+                if t_ij > 0 {
+                    R = &R.to_extended() + &table.select(t_ij as usize);
+                } else if t_ij < 0 {
+                    R = &R.to_extended() - &table.select(-t_ij as usize);
+                }
+            }
+
+            S = R.to_projective();
+        }
+
+        for naf in static_nafs.iter_mut().chain(dynamic_nafs.iter_mut()) {
+            naf[..].zeroize();
+        }
+
+        S.to_extended()
+    }
+}
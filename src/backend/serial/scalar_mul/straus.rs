@@ -15,10 +15,17 @@
 
 use core::borrow::Borrow;
 
+use backend::serial::curve_models::{CompletedPoint, ProjectiveNielsPoint, ProjectivePoint};
+use backend::serial::scalar_mul::inline_straus::interleaved_naf;
+use backend::serial::scalar_mul::pippenger::Pippenger;
+use backend::serial::scalar_mul::StatsRecorder;
+use backend::{small_non_adjacent_form, ZeroizingNafs};
 use edwards::EdwardsPoint;
 use scalar::Scalar;
+use traits::Identity;
 use traits::MultiscalarMul;
 use traits::VartimeMultiscalarMul;
+use window::NafLookupTable5;
 
 #[allow(unused_imports)]
 use prelude::*;
@@ -109,9 +116,7 @@ impl MultiscalarMul for Straus {
     {
         use zeroize::Zeroizing;
 
-        use backend::serial::curve_models::ProjectiveNielsPoint;
         use window::LookupTable;
-        use traits::Identity;
 
         let lookup_tables: Vec<_> = points
             .into_iter()
@@ -161,36 +166,171 @@ impl VartimeMultiscalarMul for Straus {
         I::Item: Borrow<Scalar>,
         J: IntoIterator<Item = Option<EdwardsPoint>>,
     {
-        use backend::serial::curve_models::{CompletedPoint, ProjectiveNielsPoint, ProjectivePoint};
-        use window::NafLookupTable5;
-        use traits::Identity;
+        Straus::optional_multiscalar_mul_recorded(scalars, points, &mut StatsRecorder::default())
+    }
+}
 
-        let nafs: Vec<_> = scalars
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect();
+impl Straus {
+    /// Like `VartimeMultiscalarMul::optional_multiscalar_mul`, counting
+    /// the group operations (including those building the lookup
+    /// tables) in `stats`.
+    pub(crate) fn optional_multiscalar_mul_recorded<I, J>(
+        scalars: I,
+        points: J,
+        stats: &mut StatsRecorder,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        let nafs = ZeroizingNafs(
+            scalars
+                .into_iter()
+                .map(|c| c.borrow().non_adjacent_form(5))
+                .collect(),
+        );
 
         let lookup_tables = points
             .into_iter()
             .map(|P_opt| P_opt.map(|P| NafLookupTable5::<ProjectiveNielsPoint>::from(&P)))
             .collect::<Option<Vec<_>>>()?;
 
-        let mut r = ProjectivePoint::identity();
+        // Each table of odd multiples costs one doubling and seven additions.
+        stats.window(5);
+        stats.doublings(lookup_tables.len());
+        stats.additions(7 * lookup_tables.len());
 
-        for i in (0..256).rev() {
-            let mut t: CompletedPoint = r.double();
+        Some(interleaved_naf(&lookup_tables, &nafs, stats))
+    }
 
-            for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
-                if naf[i] > 0 {
-                    t = &t.to_extended() + &lookup_table.select(naf[i] as usize);
-                } else if naf[i] < 0 {
-                    t = &t.to_extended() - &lookup_table.select(-naf[i] as usize);
+    /// Compute \\( \sum\_j a\_{ij} P\_{ij} \\) in variable time for
+    /// each group \\( i \\) of pairs \\( (a\_{ij}, P\_{ij}) \\).
+    ///
+    /// The buffers for the NAFs and lookup tables are allocated once,
+    /// for the largest group, and refilled for each group in turn.
+    /// Groups of `pippenger_threshold` or more pairs are instead
+    /// computed by Pippenger's method, without the buffers.
+    pub(crate) fn vartime_grouped_multiscalar_mul(
+        groups: &[&[(Scalar, EdwardsPoint)]],
+        pippenger_threshold: usize,
+    ) -> Vec<EdwardsPoint> {
+        let max_len = groups
+            .iter()
+            .map(|group| group.len())
+            .filter(|&len| len < pippenger_threshold)
+            .max()
+            .unwrap_or(0);
+        let mut nafs = ZeroizingNafs(Vec::with_capacity(max_len));
+        let mut lookup_tables: Vec<NafLookupTable5<ProjectiveNielsPoint>> =
+            Vec::with_capacity(max_len);
+
+        groups
+            .iter()
+            .map(|group| {
+                if group.len() >= pippenger_threshold {
+                    return Pippenger::vartime_multiscalar_mul(
+                        group.iter().map(|(a, _)| a),
+                        group.iter().map(|(_, P)| P),
+                    );
                 }
-            }
 
-            r = t.to_projective();
+                nafs.0.clear();
+                lookup_tables.clear();
+                nafs.0.extend(group.iter().map(|(a, _)| a.non_adjacent_form(5)));
+                lookup_tables.extend(group.iter().map(|(_, P)| NafLookupTable5::from(P)));
+
+                interleaved_naf(&lookup_tables, &nafs, &mut StatsRecorder::default())
+            })
+            .collect()
+    }
+}
+
+/// Compute \\( \sum\_i c\_i P\_i \\) for small signed coefficients
+/// \\( c\_i \\), using a short (65-digit) NAF instead of the 256-digit NAF
+/// of a full `Scalar`.
+///
+/// # Warning
+///
+/// This function is **not** constant time.
+pub fn vartime_small_multiscalar_mul(coeffs: &[i64], points: &[EdwardsPoint]) -> EdwardsPoint {
+    debug_assert_eq!(coeffs.len(), points.len());
+
+    let nafs: Vec<_> = coeffs.iter().map(|c| small_non_adjacent_form(*c)).collect();
+
+    let lookup_tables: Vec<_> = points
+        .iter()
+        .map(NafLookupTable5::<ProjectiveNielsPoint>::from)
+        .collect();
+
+    let mut r = ProjectivePoint::identity();
+
+    for i in (0..65).rev() {
+        let mut t: CompletedPoint = r.double();
+
+        for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
+            if naf[i] > 0 {
+                t = &t.to_extended() + &lookup_table.select(naf[i] as usize);
+            } else if naf[i] < 0 {
+                t = &t.to_extended() - &lookup_table.select(-naf[i] as usize);
+            }
         }
 
-        Some(r.to_extended())
+        r = t.to_projective();
+    }
+
+    r.to_extended()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use constants;
+
+    #[test]
+    fn test_interleaved_naf() {
+        let n = 16;
+        let x = Scalar::from(2128506u64).invert();
+        let y = Scalar::from(4443282u64).invert();
+        let points: Vec<_> = (0..n)
+            .map(|i| constants::ED25519_BASEPOINT_POINT * Scalar::from(1 + i as u64))
+            .collect();
+        let scalars: Vec<_> = (0..n)
+            .map(|i| x + (Scalar::from(i as u64) * y))
+            .collect();
+
+        let tables: Vec<_> = points
+            .iter()
+            .map(NafLookupTable5::<ProjectiveNielsPoint>::from)
+            .collect();
+        let nafs: Vec<_> = scalars.iter().map(|s| s.non_adjacent_form(5)).collect();
+
+        let control: EdwardsPoint = scalars
+            .iter()
+            .zip(points.iter())
+            .map(|(s, P)| s * P)
+            .sum();
+
+        assert_eq!(interleaved_naf(&tables, &nafs, &mut StatsRecorder::default()), control);
+        assert_eq!(interleaved_naf(&[], &[], &mut StatsRecorder::default()), EdwardsPoint::identity());
+    }
+
+    #[test]
+    fn test_vartime_small_multiscalar_mul() {
+        let coeffs = [0i64, 1, -1, 7, -3, 15, -16, 17, i64::max_value(), i64::min_value(), -12345678901];
+        let points: Vec<_> = (0..coeffs.len())
+            .map(|i| constants::ED25519_BASEPOINT_POINT * Scalar::from(1 + i as u64))
+            .collect();
+
+        let control: EdwardsPoint = coeffs
+            .iter()
+            .zip(points.iter())
+            .map(|(c, P)| {
+                let s = Scalar::from(c.wrapping_abs() as u64);
+                if *c < 0 { -(s * P) } else { s * P }
+            })
+            .sum();
+
+        assert_eq!(vartime_small_multiscalar_mul(&coeffs, &points), control);
     }
 }
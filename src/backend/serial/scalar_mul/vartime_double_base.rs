@@ -17,46 +17,63 @@ use edwards::EdwardsPoint;
 use backend::serial::curve_models::{ProjectiveNielsPoint, ProjectivePoint};
 use window::NafLookupTable5;
 
-/// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
-pub fn mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
-    let a_naf = a.non_adjacent_form(5);
-    let b_naf = b.non_adjacent_form(8);
-
-    // Find starting index
-    let mut i: usize = 255;
-    for j in (0..256).rev() {
-        i = j;
-        if a_naf[i] != 0 || b_naf[i] != 0 {
-            break;
-        }
-    }
+/// Precomputed odd multiples of a point \\(A\\), for repeated
+/// computations of \\(aA + bB\\) with the same \\(A\\).
+#[derive(Copy, Clone, Debug)]
+pub struct VartimeDoubleBaseTable(NafLookupTable5<ProjectiveNielsPoint>);
 
-    let table_A = NafLookupTable5::<ProjectiveNielsPoint>::from(A);
-    let table_B = &constants::AFFINE_ODD_MULTIPLES_OF_BASEPOINT;
+impl VartimeDoubleBaseTable {
+    pub fn new(A: &EdwardsPoint) -> Self {
+        VartimeDoubleBaseTable(NafLookupTable5::<ProjectiveNielsPoint>::from(A))
+    }
 
-    let mut r = ProjectivePoint::identity();
-    loop {
-        let mut t = r.double();
+    /// Compute \\(aA + bB\\) in variable time, where \\(A\\) is the
+    /// point of this table and \\(B\\) is the Ed25519 basepoint.
+    pub fn mul(&self, a: &Scalar, b: &Scalar) -> EdwardsPoint {
+        let a_naf = a.non_adjacent_form(5);
+        let b_naf = b.non_adjacent_form(8);
 
-        if a_naf[i] > 0 {
-            t = &t.to_extended() + &table_A.select(a_naf[i] as usize);
-        } else if a_naf[i] < 0 {
-            t = &t.to_extended() - &table_A.select(-a_naf[i] as usize);
+        // Find starting index
+        let mut i: usize = 255;
+        for j in (0..256).rev() {
+            i = j;
+            if a_naf[i] != 0 || b_naf[i] != 0 {
+                break;
+            }
         }
 
-        if b_naf[i] > 0 {
-            t = &t.to_extended() + &table_B.select(b_naf[i] as usize);
-        } else if b_naf[i] < 0 {
-            t = &t.to_extended() - &table_B.select(-b_naf[i] as usize);
-        }
+        let table_A = &self.0;
+        let table_B = &constants::AFFINE_ODD_MULTIPLES_OF_BASEPOINT;
+
+        let mut r = ProjectivePoint::identity();
+        loop {
+            let mut t = r.double();
 
-        r = t.to_projective();
+            if a_naf[i] > 0 {
+                t = &t.to_extended() + &table_A.select(a_naf[i] as usize);
+            } else if a_naf[i] < 0 {
+                t = &t.to_extended() - &table_A.select(-a_naf[i] as usize);
+            }
 
-        if i == 0 {
-            break;
+            if b_naf[i] > 0 {
+                t = &t.to_extended() + &table_B.select(b_naf[i] as usize);
+            } else if b_naf[i] < 0 {
+                t = &t.to_extended() - &table_B.select(-b_naf[i] as usize);
+            }
+
+            r = t.to_projective();
+
+            if i == 0 {
+                break;
+            }
+            i -= 1;
         }
-        i -= 1;
+
+        r.to_extended()
     }
+}
 
-    r.to_extended()
+/// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
+pub fn mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
+    VartimeDoubleBaseTable::new(A).mul(a, b)
 }
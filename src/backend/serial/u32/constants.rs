@@ -76,6 +76,13 @@ pub(crate) const MONTGOMERY_A_NEG: FieldElement2625 = FieldElement2625([
     66622183, 33554431, 67108863, 33554431, 67108863, 33554431, 67108863, 33554431, 67108863, 33554431,
 ]);
 
+/// `SQRT_MINUS_APLUS2` is the square root of -(A+2) = -486664 used in
+/// the birational map from Edwards to Montgomery coordinates, chosen so
+/// that the Ed25519 basepoint maps to the Montgomery basepoint of RFC 7748.
+pub(crate) const SQRT_MINUS_APLUS2: FieldElement2625 = FieldElement2625([
+    12222951, 8312128, 11511410, 24486935, 15300784, 241793, 41652734, 19432880, 12187135, 29582408,
+]);
+
 /// `L` is the order of base point, i.e. 2^252 +
 /// 27742317777372353535851937790883648493
 pub(crate) const L: Scalar29 = Scalar29([
@@ -107,6 +107,17 @@ pub(crate) const MONTGOMERY_A_NEG: FieldElement51 = FieldElement51([
     2251799813685247,
 ]);
 
+/// `SQRT_MINUS_APLUS2` is the square root of -(A+2) = -486664 used in
+/// the birational map from Edwards to Montgomery coordinates, chosen so
+/// that the Ed25519 basepoint maps to the Montgomery basepoint of RFC 7748.
+pub(crate) const SQRT_MINUS_APLUS2: FieldElement51 = FieldElement51([
+    557817479725543,
+    1643290402203250,
+    16226468853936,
+    1304118542701054,
+    1985241807451647,
+]);
+
 /// `L` is the order of base point, i.e. 2^252 + 27742317777372353535851937790883648493
 pub(crate) const L: Scalar52 = Scalar52([
     0x0002631a5cf5d3ed,
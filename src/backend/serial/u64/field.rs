@@ -39,6 +39,7 @@ use zeroize::Zeroize;
 /// The backend-specific type `FieldElement51` should not be used
 /// outside of the `curve25519_dalek::field` module.
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "ffi", repr(transparent))]
 pub struct FieldElement51(pub (crate) [u64; 5]);
 
 impl Debug for FieldElement51 {
@@ -0,0 +1,93 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Variable-time Straus multiscalar multiplication of a few points,
+//! keeping the NAFs and lookup tables on the stack.  See the
+//! `inline_straus` module in the serial backend for more details.
+
+#![allow(non_snake_case)]
+
+use backend::vector::{CachedPoint, ExtendedPoint};
+use edwards::EdwardsPoint;
+use scalar::Scalar;
+use traits::Identity;
+use window::NafLookupTable5;
+use zeroize::Zeroize;
+
+/// The largest number of points accepted by `vartime_multiscalar_mul`.
+pub const MAX_POINTS: usize = 3;
+
+/// Compute \\( \sum\_i s\_i P\_i \\) in variable time for at most
+/// `MAX_POINTS` points, by Straus' method with width-\\(5\\) NAFs.
+///
+/// # Panics
+///
+/// Panics if the lengths of `scalars` and `points` differ, or exceed
+/// `MAX_POINTS`.
+pub fn vartime_multiscalar_mul(scalars: &[Scalar], points: &[EdwardsPoint]) -> EdwardsPoint {
+    assert_eq!(scalars.len(), points.len());
+    assert!(points.len() <= MAX_POINTS);
+    let n = points.len();
+
+    let mut nafs = [[0i8; 256]; MAX_POINTS];
+    let mut lookup_tables =
+        [NafLookupTable5::<CachedPoint>::from(&EdwardsPoint::identity()); MAX_POINTS];
+    for (naf, s) in nafs.iter_mut().zip(scalars.iter()) {
+        *naf = s.non_adjacent_form(5);
+    }
+    for (table, P) in lookup_tables.iter_mut().zip(points.iter()) {
+        *table = NafLookupTable5::<CachedPoint>::from(P);
+    }
+
+    let result = interleaved_naf(&lookup_tables[..n], &nafs[..n]);
+
+    for naf in nafs.iter_mut() {
+        naf[..].zeroize();
+    }
+
+    result
+}
+
+/// Compute \\( \sum\_i n\_i P\_i \\) by interleaved double-and-add,
+/// given the width-\\(5\\) NAF digits \\( n\_i \\) of the scalars and
+/// lookup tables of odd multiples of the points \\( P\_i \\).
+///
+/// There must be one table for each NAF, in the same order.
+///
+/// # Warning
+///
+/// This function is **not** constant time, since it branches on the
+/// NAF digits.
+pub(crate) fn interleaved_naf(
+    tables: &[NafLookupTable5<CachedPoint>],
+    nafs: &[[i8; 256]],
+) -> EdwardsPoint {
+    debug_assert_eq!(tables.len(), nafs.len());
+
+    // Skip the leading rows where every NAF coefficient is zero.
+    let mut max_i: usize = 255;
+    for i in (0..256).rev() {
+        max_i = i;
+        if nafs.iter().any(|naf| naf[i] != 0) {
+            break;
+        }
+    }
+
+    let mut Q = ExtendedPoint::identity();
+
+    for i in (0..=max_i).rev() {
+        Q = Q.double();
+
+        for (naf, lookup_table) in nafs.iter().zip(tables.iter()) {
+            if naf[i] > 0 {
+                Q = &Q + &lookup_table.select(naf[i] as usize);
+            } else if naf[i] < 0 {
+                Q = &Q - &lookup_table.select(-naf[i] as usize);
+            }
+        }
+    }
+
+    Q.into()
+}
@@ -13,11 +13,16 @@ pub mod variable_base;
 
 pub mod vartime_double_base;
 
+pub mod inline_straus;
+
 #[cfg(feature = "alloc")]
 pub mod straus;
 
 #[cfg(feature = "alloc")]
 pub mod precomputed_straus;
 
+#[cfg(feature = "const_generics")]
+pub mod precomputed_straus_array;
+
 #[cfg(feature = "alloc")]
 pub mod pippenger;
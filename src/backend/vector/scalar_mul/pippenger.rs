@@ -44,6 +44,30 @@ impl VartimeMultiscalarMul for Pippenger {
             8
         };
 
+        Pippenger::optional_multiscalar_mul_with_window(scalars, points, w)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl Pippenger {
+    /// Like `optional_multiscalar_mul`, but with a digit width of `w`
+    /// bits, rather than one chosen from the number of inputs.
+    ///
+    /// # Panics
+    ///
+    /// If `w` is not between 4 and 8, inclusive.
+    pub fn optional_multiscalar_mul_with_window<I, J>(
+        scalars: I,
+        points: J,
+        w: usize,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        assert!((4..=8).contains(&w), "Pippenger window must be between 4 and 8 bits");
+
         let max_digit: usize = 1 << w;
         let digits_count: usize = Scalar::to_radix_2w_size_hint(w);
         let buckets_count: usize = max_digit / 2; // digits are signed+centered hence 2^w/2, excluding 0-th bucket
@@ -14,6 +14,7 @@
 use core::borrow::Borrow;
 
 use backend::vector::{CachedPoint, ExtendedPoint};
+use backend::ZeroizingNafs;
 use edwards::EdwardsPoint;
 use scalar::Scalar;
 use traits::Identity;
@@ -28,6 +29,55 @@ pub struct VartimePrecomputedStraus {
     static_lookup_tables: Vec<NafLookupTable8<CachedPoint>>,
 }
 
+impl VartimePrecomputedStraus {
+    pub fn len(&self) -> usize {
+        self.static_lookup_tables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.static_lookup_tables.is_empty()
+    }
+
+    /// The number of heap bytes used by the lookup tables for
+    /// `num_points` static points.
+    pub fn footprint_bytes(num_points: usize) -> usize {
+        num_points * ::core::mem::size_of::<NafLookupTable8<CachedPoint>>()
+    }
+
+    /// The number of heap bytes allocated for the lookup tables, which
+    /// may exceed `footprint_bytes(self.len())` after an `append`.
+    pub fn footprint(&self) -> usize {
+        Self::footprint_bytes(self.static_lookup_tables.capacity())
+    }
+
+    /// Move the lookup tables of `other` onto the end of `self`.
+    pub fn append(&mut self, mut other: Self) {
+        self.static_lookup_tables.append(&mut other.static_lookup_tables);
+    }
+
+    /// Concatenate precomputations, in order, without rebuilding their
+    /// lookup tables.
+    pub fn concat<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        Self {
+            static_lookup_tables: parts
+                .into_iter()
+                .flat_map(|part| part.static_lookup_tables)
+                .collect(),
+        }
+    }
+
+    /// Reconstruct the static points from the first entry, \\(1B\_i\\),
+    /// of each lookup table.
+    pub fn points<'a>(&'a self) -> impl Iterator<Item = EdwardsPoint> + 'a {
+        self.static_lookup_tables
+            .iter()
+            .map(|table| (&ExtendedPoint::identity() + &table.select(1)).into())
+    }
+}
+
 impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
     type Point = EdwardsPoint;
 
@@ -57,15 +107,32 @@ impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
         J::Item: Borrow<Scalar>,
         K: IntoIterator<Item = Option<Self::Point>>,
     {
-        let static_nafs = static_scalars
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect::<Vec<_>>();
-        let dynamic_nafs: Vec<_> = dynamic_scalars
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect::<Vec<_>>();
-
+        let static_nafs = ZeroizingNafs(
+            static_scalars
+                .into_iter()
+                .map(|c| c.borrow().non_adjacent_form(5))
+                .collect(),
+        );
+        let dynamic_nafs = ZeroizingNafs(
+            dynamic_scalars
+                .into_iter()
+                .map(|c| c.borrow().non_adjacent_form(5))
+                .collect(),
+        );
+
+        // The tables for the dynamic points are independent of each
+        // other, so they can be built in parallel.
+        #[cfg(feature = "parallel")]
+        let dynamic_lookup_tables = {
+            use rayon::prelude::*;
+
+            let dynamic_points: Vec<Option<EdwardsPoint>> = dynamic_points.into_iter().collect();
+            dynamic_points
+                .par_iter()
+                .map(|P_opt| P_opt.as_ref().map(NafLookupTable5::<CachedPoint>::from))
+                .collect::<Option<Vec<_>>>()?
+        };
+        #[cfg(not(feature = "parallel"))]
         let dynamic_lookup_tables = dynamic_points
             .into_iter()
             .map(|P_opt| P_opt.map(|P| NafLookupTable5::<CachedPoint>::from(&P)))
@@ -76,11 +143,20 @@ impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
         assert_eq!(sp, static_nafs.len());
         assert_eq!(dp, dynamic_nafs.len());
 
-        // We could save some doublings by looking for the highest
-        // nonzero NAF coefficient, but since we might have a lot of
-        // them to search, it's not clear it's worthwhile to check.
-        let mut R = ExtendedPoint::identity();
+        // Skip the leading rows where every NAF coefficient is zero.
+        // Scanning a row costs no more than processing it in the main
+        // loop, minus its doubling, so this is never much slower, and
+        // saves many doublings when all the scalars are short.
+        let mut max_j: usize = 255;
         for j in (0..256).rev() {
+            max_j = j;
+            if dynamic_nafs.iter().chain(static_nafs.iter()).any(|naf| naf[j] != 0) {
+                break;
+            }
+        }
+
+        let mut R = ExtendedPoint::identity();
+        for j in (0..=max_j).rev() {
             R = R.double();
 
             for i in 0..dp {
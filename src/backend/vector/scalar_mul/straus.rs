@@ -16,6 +16,9 @@ use core::borrow::Borrow;
 use zeroize::Zeroizing;
 
 use backend::vector::{CachedPoint, ExtendedPoint};
+use backend::vector::scalar_mul::inline_straus::interleaved_naf;
+use backend::vector::scalar_mul::pippenger::Pippenger;
+use backend::{small_non_adjacent_form, ZeroizingNafs};
 use edwards::EdwardsPoint;
 use scalar::Scalar;
 use window::{LookupTable, NafLookupTable5};
@@ -80,29 +83,90 @@ impl VartimeMultiscalarMul for Straus {
         I::Item: Borrow<Scalar>,
         J: IntoIterator<Item = Option<EdwardsPoint>>,
     {
-        let nafs: Vec<_> = scalars
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect();
+        let nafs = ZeroizingNafs(
+            scalars
+                .into_iter()
+                .map(|c| c.borrow().non_adjacent_form(5))
+                .collect(),
+        );
         let lookup_tables: Vec<_> = points
             .into_iter()
             .map(|P_opt| P_opt.map(|P| NafLookupTable5::<CachedPoint>::from(&P)))
             .collect::<Option<Vec<_>>>()?;
 
-        let mut Q = ExtendedPoint::identity();
-
-        for i in (0..256).rev() {
-            Q = Q.double();
+        Some(interleaved_naf(&lookup_tables, &nafs))
+    }
+}
 
-            for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
-                if naf[i] > 0 {
-                    Q = &Q + &lookup_table.select(naf[i] as usize);
-                } else if naf[i] < 0 {
-                    Q = &Q - &lookup_table.select(-naf[i] as usize);
+impl Straus {
+    /// Compute \\( \sum\_j a\_{ij} P\_{ij} \\) in variable time for
+    /// each group \\( i \\) of pairs \\( (a\_{ij}, P\_{ij}) \\), reusing
+    /// the NAF and lookup table buffers across groups.  See the serial
+    /// backend's `Straus::vartime_grouped_multiscalar_mul`.
+    pub(crate) fn vartime_grouped_multiscalar_mul(
+        groups: &[&[(Scalar, EdwardsPoint)]],
+        pippenger_threshold: usize,
+    ) -> Vec<EdwardsPoint> {
+        let max_len = groups
+            .iter()
+            .map(|group| group.len())
+            .filter(|&len| len < pippenger_threshold)
+            .max()
+            .unwrap_or(0);
+        let mut nafs = ZeroizingNafs(Vec::with_capacity(max_len));
+        let mut lookup_tables: Vec<NafLookupTable5<CachedPoint>> = Vec::with_capacity(max_len);
+
+        groups
+            .iter()
+            .map(|group| {
+                if group.len() >= pippenger_threshold {
+                    return Pippenger::vartime_multiscalar_mul(
+                        group.iter().map(|(a, _)| a),
+                        group.iter().map(|(_, P)| P),
+                    );
                 }
+
+                nafs.0.clear();
+                lookup_tables.clear();
+                nafs.0.extend(group.iter().map(|(a, _)| a.non_adjacent_form(5)));
+                lookup_tables.extend(group.iter().map(|(_, P)| NafLookupTable5::from(P)));
+
+                interleaved_naf(&lookup_tables, &nafs)
+            })
+            .collect()
+    }
+}
+
+/// Compute \\( \sum\_i c\_i P\_i \\) for small signed coefficients
+/// \\( c\_i \\), using a short (65-digit) NAF.  See
+/// `vartime_small_multiscalar_mul` in the serial backend.
+///
+/// # Warning
+///
+/// This function is **not** constant time.
+pub fn vartime_small_multiscalar_mul(coeffs: &[i64], points: &[EdwardsPoint]) -> EdwardsPoint {
+    debug_assert_eq!(coeffs.len(), points.len());
+
+    let nafs: Vec<_> = coeffs.iter().map(|c| small_non_adjacent_form(*c)).collect();
+
+    let lookup_tables: Vec<_> = points
+        .iter()
+        .map(NafLookupTable5::<CachedPoint>::from)
+        .collect();
+
+    let mut Q = ExtendedPoint::identity();
+
+    for i in (0..65).rev() {
+        Q = Q.double();
+
+        for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
+            if naf[i] > 0 {
+                Q = &Q + &lookup_table.select(naf[i] as usize);
+            } else if naf[i] < 0 {
+                Q = &Q - &lookup_table.select(-naf[i] as usize);
             }
         }
-
-        Some(Q.into())
     }
+
+    Q.into()
 }
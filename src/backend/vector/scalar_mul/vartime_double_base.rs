@@ -18,45 +18,62 @@ use scalar::Scalar;
 use traits::Identity;
 use window::NafLookupTable5;
 
-/// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
-pub fn mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
-    let a_naf = a.non_adjacent_form(5);
-    let b_naf = b.non_adjacent_form(8);
-
-    // Find starting index
-    let mut i: usize = 255;
-    for j in (0..256).rev() {
-        i = j;
-        if a_naf[i] != 0 || b_naf[i] != 0 {
-            break;
-        }
+/// Precomputed odd multiples of a point \\(A\\), for repeated
+/// computations of \\(aA + bB\\) with the same \\(A\\).
+#[derive(Copy, Clone, Debug)]
+pub struct VartimeDoubleBaseTable(NafLookupTable5<CachedPoint>);
+
+impl VartimeDoubleBaseTable {
+    pub fn new(A: &EdwardsPoint) -> Self {
+        VartimeDoubleBaseTable(NafLookupTable5::<CachedPoint>::from(A))
     }
 
-    let table_A = NafLookupTable5::<CachedPoint>::from(A);
-    let table_B = &BASEPOINT_ODD_LOOKUP_TABLE;
+    /// Compute \\(aA + bB\\) in variable time, where \\(A\\) is the
+    /// point of this table and \\(B\\) is the Ed25519 basepoint.
+    pub fn mul(&self, a: &Scalar, b: &Scalar) -> EdwardsPoint {
+        let a_naf = a.non_adjacent_form(5);
+        let b_naf = b.non_adjacent_form(8);
 
-    let mut Q = ExtendedPoint::identity();
+        // Find starting index
+        let mut i: usize = 255;
+        for j in (0..256).rev() {
+            i = j;
+            if a_naf[i] != 0 || b_naf[i] != 0 {
+                break;
+            }
+        }
 
-    loop {
-        Q = Q.double();
+        let table_A = &self.0;
+        let table_B = &BASEPOINT_ODD_LOOKUP_TABLE;
 
-        if a_naf[i] > 0 {
-            Q = &Q + &table_A.select(a_naf[i] as usize);
-        } else if a_naf[i] < 0 {
-            Q = &Q - &table_A.select(-a_naf[i] as usize);
-        }
+        let mut Q = ExtendedPoint::identity();
 
-        if b_naf[i] > 0 {
-            Q = &Q + &table_B.select(b_naf[i] as usize);
-        } else if b_naf[i] < 0 {
-            Q = &Q - &table_B.select(-b_naf[i] as usize);
-        }
+        loop {
+            Q = Q.double();
 
-        if i == 0 {
-            break;
+            if a_naf[i] > 0 {
+                Q = &Q + &table_A.select(a_naf[i] as usize);
+            } else if a_naf[i] < 0 {
+                Q = &Q - &table_A.select(-a_naf[i] as usize);
+            }
+
+            if b_naf[i] > 0 {
+                Q = &Q + &table_B.select(b_naf[i] as usize);
+            } else if b_naf[i] < 0 {
+                Q = &Q - &table_B.select(-b_naf[i] as usize);
+            }
+
+            if i == 0 {
+                break;
+            }
+            i -= 1;
         }
-        i -= 1;
+
+        Q.into()
     }
+}
 
-    Q.into()
+/// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
+pub fn mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
+    VartimeDoubleBaseTable::new(A).mul(a, b)
 }
@@ -0,0 +1,209 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! A minimal transcript for deriving Fiat-Shamir challenges.
+//!
+//! A [`ChallengeHasher`] absorbs a sequence of points, scalars and byte
+//! strings, and hashes them to a challenge `Scalar`.  Each item is
+//! framed as its length, as an 8-byte little-endian `u64`, followed by
+//! its encoding:
+//!
+//! * an `EdwardsPoint` is encoded as its 32-byte compressed form;
+//! * a `Scalar` is encoded as its 32 canonical little-endian bytes;
+//! * a byte string is encoded as itself;
+//! * a slice of points or scalars is encoded as the concatenation of
+//!   the encodings of its elements, in order.
+//!
+//! The challenge is the 64-byte digest of the concatenated frames,
+//! reduced modulo \\( \ell \\) as by `Scalar::from_hash`.  The framing
+//! makes the encoding of the transcript injective, so for instance
+//! appending `b"ab"` and then `b"c"` gives a different challenge from
+//! appending `b"a"` and then `b"bc"`.
+//!
+//! The framing does not record the type of each item, so a protocol
+//! should append its items in a fixed order, starting with a domain
+//! separation label.
+
+#![allow(non_snake_case)]
+
+use digest::generic_array::typenum::U64;
+use digest::Digest;
+
+use edwards::EdwardsPoint;
+use scalar::Scalar;
+
+/// A Fiat-Shamir transcript over the hash function `D`, which is any
+/// `Digest` producing 64 bytes of output, such as `Sha512`.
+///
+/// # Example
+///
+/// ```
+/// # extern crate curve25519_dalek;
+/// # extern crate sha2;
+/// use curve25519_dalek::challenge::ChallengeHasher;
+/// use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+/// use curve25519_dalek::scalar::Scalar;
+/// use sha2::Sha512;
+///
+/// # fn main() {
+/// let r = Scalar::from(1234u64);
+/// let R = ED25519_BASEPOINT_POINT * r;
+///
+/// let c = ChallengeHasher::<Sha512>::new()
+///     .append_bytes(b"my-protocol-v1")
+///     .append_point(&R)
+///     .append_bytes(b"message")
+///     .finalize();
+/// # let _ = c;
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChallengeHasher<D> {
+    hash: D,
+}
+
+impl<D> Default for ChallengeHasher<D>
+where
+    D: Digest<OutputSize = U64> + Default,
+{
+    fn default() -> Self {
+        ChallengeHasher::new()
+    }
+}
+
+impl<D> ChallengeHasher<D>
+where
+    D: Digest<OutputSize = U64> + Default,
+{
+    /// Start an empty transcript.
+    pub fn new() -> Self {
+        ChallengeHasher { hash: D::default() }
+    }
+
+    fn append_frame(&mut self, bytes: &[u8]) {
+        self.hash.update((bytes.len() as u64).to_le_bytes());
+        self.hash.update(bytes);
+    }
+
+    /// Append the compressed encoding of the point `P`.
+    pub fn append_point(&mut self, P: &EdwardsPoint) -> &mut Self {
+        self.append_frame(P.compress().as_bytes());
+        self
+    }
+
+    /// Append the 32 bytes of the scalar `s`, as returned by
+    /// `Scalar::as_bytes`.  These are absorbed as they are, without
+    /// reducing \\( s \\) modulo \\( \ell \\) first.
+    /// Scalars produced by arithmetic or by `from_bytes_mod_order` are
+    /// already reduced, but one built with `Scalar::from_bits` may not be.
+    pub fn append_scalar(&mut self, s: &Scalar) -> &mut Self {
+        self.append_frame(s.as_bytes());
+        self
+    }
+
+    /// Append the compressed encodings of `points`, as one item, with
+    /// `EdwardsPoint::hash_many`.
+    pub fn append_points(&mut self, points: &[EdwardsPoint]) -> &mut Self {
+        EdwardsPoint::hash_many(points, &mut self.hash);
+        self
+    }
+
+    /// Append the bytes of `scalars`, as one item, with
+    /// `Scalar::hash_many`.  Like `append_scalar`, this does not reduce
+    /// the scalars first.
+    pub fn append_scalars(&mut self, scalars: &[Scalar]) -> &mut Self {
+        Scalar::hash_many(scalars, &mut self.hash);
+        self
+    }
+
+    /// Append the byte string `bytes`.
+    pub fn append_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.append_frame(bytes);
+        self
+    }
+
+    /// Hash the transcript to a challenge scalar.
+    ///
+    /// This consumes a copy of the hash state, so the transcript can be
+    /// extended further to derive later challenges.
+    pub fn finalize(&self) -> Scalar
+    where
+        D: Clone,
+    {
+        Scalar::from_hash(self.hash.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use constants;
+    use prelude::*;
+
+    #[test]
+    fn challenge_vector() {
+        let c = ChallengeHasher::<sha2::Sha512>::new()
+            .append_bytes(b"test")
+            .append_point(&constants::ED25519_BASEPOINT_POINT)
+            .append_scalar(&Scalar::one())
+            .append_bytes(b"")
+            .finalize();
+        assert_eq!(
+            hex::encode(c.as_bytes()),
+            "c5ed3bf79799561acb2a3203f9ac7be97c09bb1147e322c66dcb15bd25540104"
+        );
+    }
+
+    #[test]
+    fn framing_separates_items() {
+        let mut ab_c = ChallengeHasher::<sha2::Sha512>::new();
+        ab_c.append_bytes(b"ab").append_bytes(b"c");
+        let mut a_bc = ChallengeHasher::<sha2::Sha512>::new();
+        a_bc.append_bytes(b"a").append_bytes(b"bc");
+        assert_ne!(ab_c.finalize(), a_bc.finalize());
+
+        // Finalizing does not consume the transcript.
+        let first = ab_c.finalize();
+        assert_eq!(ab_c.finalize(), first);
+        ab_c.append_scalar(&first);
+        assert_ne!(ab_c.finalize(), first);
+    }
+
+    #[test]
+    fn vectors_are_framed_as_one_item() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let points = [B, B + B, EdwardsPoint::default()];
+        let scalars = [Scalar::one(), Scalar::zero()];
+
+        let mut point_bytes = Vec::new();
+        for P in points.iter() {
+            point_bytes.extend_from_slice(P.compress().as_bytes());
+        }
+        let mut scalar_bytes = Vec::new();
+        for s in scalars.iter() {
+            scalar_bytes.extend_from_slice(s.as_bytes());
+        }
+
+        let c = ChallengeHasher::<sha2::Sha512>::new()
+            .append_points(&points)
+            .append_scalars(&scalars)
+            .finalize();
+        let expected = ChallengeHasher::<sha2::Sha512>::new()
+            .append_bytes(&point_bytes)
+            .append_bytes(&scalar_bytes)
+            .finalize();
+        assert_eq!(c, expected);
+
+        // Moving an element between the vectors changes the challenge.
+        let moved = ChallengeHasher::<sha2::Sha512>::new()
+            .append_points(&points[..2])
+            .append_points(&points[2..])
+            .append_scalars(&scalars)
+            .finalize();
+        assert_ne!(c, moved);
+        let empty = ChallengeHasher::<sha2::Sha512>::new().append_scalars(&[]).finalize();
+        assert_eq!(empty, ChallengeHasher::<sha2::Sha512>::new().append_bytes(b"").finalize());
+    }
+}
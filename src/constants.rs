@@ -29,6 +29,7 @@
 #![allow(non_snake_case)]
 
 use edwards::CompressedEdwardsY;
+use edwards::EdwardsPoint;
 use ristretto::RistrettoPoint;
 use ristretto::CompressedRistretto;
 use montgomery::MontgomeryPoint;
@@ -88,6 +89,50 @@ pub const BASEPOINT_ORDER: Scalar = Scalar{
     ],
 };
 
+/// `INV_EIGHT` is the inverse of eight modulo \\( \ell \\), i.e., the scalar
+/// \\( 8\^\{-1\} \\) with \\( 8 \cdot 8\^\{-1\} \equiv 1 \pmod \ell \\).
+///
+/// This is useful for protocols which multiply by the cofactor and need
+/// to undo that multiplication on the prime-order component.
+pub const INV_EIGHT: Scalar = Scalar{
+    bytes: [
+        0x79, 0x2f, 0xdc, 0xe2, 0x29, 0xe5, 0x06, 0x61,
+        0xd0, 0xda, 0x1c, 0x7d, 0xb3, 0x9d, 0xd3, 0x07,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06,
+    ],
+};
+
+/// `TWO_INV` is the inverse of two modulo \\( \ell \\), i.e.,
+/// \\( (\ell + 1) / 2 \\).
+pub const TWO_INV: Scalar = Scalar{
+    bytes: [
+        0xf7, 0xe9, 0x7a, 0x2e, 0x8d, 0x31, 0x09, 0x2c,
+        0x6b, 0xce, 0x7b, 0x51, 0xef, 0x7c, 0x6f, 0x0a,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    ],
+};
+
+/// The 4-torsion subgroup \\(\mathcal E [4]\\), i.e. the points of
+/// `EIGHT_TORSION` with even index.
+///
+/// The \\(i\\)-th element is \\([2i]P\\), where \\(P\\) is
+/// `EIGHT_TORSION[1]`, so `FOUR_TORSION[1]` generates \\(\mathcal E[4]\\).
+pub const FOUR_TORSION: [EdwardsPoint; 4] = [
+    EIGHT_TORSION[0],
+    EIGHT_TORSION[2],
+    EIGHT_TORSION[4],
+    EIGHT_TORSION[6],
+];
+
+/// The 2-torsion subgroup \\(\mathcal E [2]\\), namely the identity
+/// and the point \\((0, -1)\\) of order \\(2\\).
+pub const TWO_TORSION: [EdwardsPoint; 2] = [
+    EIGHT_TORSION[0],
+    EIGHT_TORSION[4],
+];
+
 use ristretto::RistrettoBasepointTable;
 /// The Ristretto basepoint, as a `RistrettoBasepointTable` for scalar multiplication.
 pub const RISTRETTO_BASEPOINT_TABLE: RistrettoBasepointTable
@@ -126,6 +171,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_torsion_subgroup_orders() {
+        use edwards::PointOrder;
+
+        let orders = [
+            PointOrder::One, PointOrder::Eight, PointOrder::Four, PointOrder::Eight,
+            PointOrder::Two, PointOrder::Eight, PointOrder::Four, PointOrder::Eight,
+        ];
+        for (P, order) in constants::EIGHT_TORSION.iter().zip(orders.iter()) {
+            assert_eq!(P.order(), *order);
+        }
+        for (i, P) in constants::FOUR_TORSION.iter().enumerate() {
+            assert_eq!(*P, constants::EIGHT_TORSION[2 * i]);
+            assert!(P.mul_by_pow_2(2).is_identity());
+        }
+        for (i, P) in constants::TWO_TORSION.iter().enumerate() {
+            assert_eq!(*P, constants::EIGHT_TORSION[4 * i]);
+            assert!(P.mul_by_pow_2(1).is_identity());
+        }
+    }
+
     /// Test that SQRT_M1 is the positive square root of -1
     #[test]
     fn test_sqrt_minus_one() {
@@ -170,6 +236,22 @@ mod test {
         assert_eq!(d2, constants::EDWARDS_D2);
     }
 
+    #[test]
+    fn test_inv_eight() {
+        use scalar::Scalar;
+        assert_eq!(constants::INV_EIGHT * Scalar::from(8u8), Scalar::one());
+        assert_eq!(constants::INV_EIGHT, Scalar::from(8u8).invert());
+        assert!(constants::INV_EIGHT.is_canonical());
+    }
+
+    #[test]
+    fn test_two_inv() {
+        use scalar::Scalar;
+        assert_eq!(constants::TWO_INV * Scalar::from(2u8), Scalar::one());
+        assert_eq!(constants::TWO_INV, Scalar::from(2u8).invert());
+        assert!(constants::TWO_INV.is_canonical());
+    }
+
     #[test]
     fn test_sqrt_ad_minus_one() {
         let a = FieldElement::minus_one();
@@ -178,4 +260,11 @@ mod test {
         assert_eq!(should_be_ad_minus_one, ad_minus_one);
     }
 
+    #[test]
+    fn test_sqrt_minus_aplus2() {
+        let two = &FieldElement::one() + &FieldElement::one();
+        let minus_aplus2 = -&(&constants::MONTGOMERY_A + &two);
+        assert_eq!(constants::SQRT_MINUS_APLUS2.square(), minus_aplus2);
+    }
+
 }
@@ -100,12 +100,16 @@ use core::iter::Sum;
 use core::ops::{Add, Neg, Sub};
 use core::ops::{AddAssign, SubAssign};
 use core::ops::{Mul, MulAssign};
+#[cfg(feature = "alloc")]
+use core::ops::Range;
 
 use digest::{generic_array::typenum::U64, Digest};
+use rand_core::{CryptoRng, RngCore};
 use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
 use zeroize::Zeroize;
 
@@ -151,6 +155,11 @@ use backend::serial::scalar_mul;
 ))]
 use backend::vector::scalar_mul;
 
+/// Variable-time multiscalar multiplications of fewer than this many
+/// points use Straus' method, and larger ones use Pippenger's.
+#[cfg(feature = "alloc")]
+const STRAUS_PIPPENGER_THRESHOLD: usize = 190;
+
 // ------------------------------------------------------------------------
 // Compressed points
 // ------------------------------------------------------------------------
@@ -191,12 +200,7 @@ impl CompressedEdwardsY {
     /// Returns `None` if the input is not the \\(y\\)-coordinate of a
     /// curve point.
     pub fn decompress(&self) -> Option<EdwardsPoint> {
-        let Y = FieldElement::from_bytes(self.as_bytes());
-        let Z = FieldElement::one();
-        let YY = Y.square();
-        let u = &YY - &Z;                            // u =  y²-1
-        let v = &(&YY * &constants::EDWARDS_D) + &Z; // v = dy²+1
-        let (is_valid_y_coord, mut X) = FieldElement::sqrt_ratio_i(&u, &v);
+        let (is_valid_y_coord, mut X, Y, Z) = self.decompress_step_1();
 
         if is_valid_y_coord.unwrap_u8() != 1u8 { return None; }
 
@@ -207,6 +211,83 @@ impl CompressedEdwardsY {
 
         Some(EdwardsPoint{ X, Y, Z, T: &X * &Y })
     }
+
+    /// Attempt to decompress to an `EdwardsPoint`, returning a
+    /// `CtOption` instead of an `Option`.
+    ///
+    /// This performs the same computation as `decompress()`, but does
+    /// not branch on whether the input is valid, so that the result can
+    /// be combined with further constant-time logic in protocols where
+    /// the validity of the encoding is itself secret.
+    ///
+    /// The result is none if the input is not the \\(y\\)-coordinate of
+    /// a curve point.
+    pub fn decompress_ct(&self) -> CtOption<EdwardsPoint> {
+        let (is_valid_y_coord, mut X, Y, Z) = self.decompress_step_1();
+
+        let compressed_sign_bit = Choice::from(self.as_bytes()[31] >> 7);
+        X.conditional_negate(compressed_sign_bit);
+
+        CtOption::new(EdwardsPoint{ X, Y, Z, T: &X * &Y }, is_valid_y_coord)
+    }
+
+    /// Attempt to decompress to an `EdwardsPoint`, in variable time.
+    ///
+    /// This gives the same result as `decompress()`, but branches on
+    /// the sign bit instead of using a constant-time conditional
+    /// negation, so it should only be used on public data, e.g. when
+    /// verifying signatures.
+    ///
+    /// Note that the cost of decompression is dominated by the square
+    /// root computation, which is the same in both versions, so the
+    /// savings per point are small.
+    ///
+    /// Returns `None` if the input is not the \\(y\\)-coordinate of a
+    /// curve point.
+    pub fn decompress_vartime(&self) -> Option<EdwardsPoint> {
+        let (is_valid_y_coord, X, Y, Z) = self.decompress_step_1();
+
+        if is_valid_y_coord.unwrap_u8() != 1u8 { return None; }
+
+        let X = if self.as_bytes()[31] >> 7 == 1 { -&X } else { X };
+
+        Some(EdwardsPoint{ X, Y, Z, T: &X * &Y })
+    }
+
+    /// Attempt to decompress to an `EdwardsPoint`, and multiply the
+    /// result by `scalar`, in constant time.
+    ///
+    /// Returns `None` if the input is not the \\(y\\)-coordinate of a
+    /// curve point.
+    pub fn decompress_then_mul(&self, scalar: &Scalar) -> Option<EdwardsPoint> {
+        self.decompress().map(|P| P * scalar)
+    }
+
+    /// Attempt to decompress to an `EdwardsPoint`, and multiply the
+    /// result by `scalar`, in variable time.
+    ///
+    /// This should only be used when both the encoding and the scalar
+    /// are public, e.g. when verifying signatures.
+    ///
+    /// Returns `None` if the input is not the \\(y\\)-coordinate of a
+    /// curve point.
+    pub fn vartime_decompress_then_mul(&self, scalar: &Scalar) -> Option<EdwardsPoint> {
+        self.decompress_vartime()
+            .map(|P| P.vartime_mul(scalar))
+    }
+
+    /// Recover the nonnegative \\(x\\)-coordinate for the encoded
+    /// \\(y\\)-coordinate, returning `(is_valid_y_coord, X, Y, Z)`.
+    fn decompress_step_1(&self) -> (Choice, FieldElement, FieldElement, FieldElement) {
+        let Y = FieldElement::from_bytes(self.as_bytes());
+        let Z = FieldElement::one();
+        let YY = Y.square();
+        let u = &YY - &Z;                            // u =  y²-1
+        let v = &(&YY * &constants::EDWARDS_D) + &Z; // v = dy²+1
+        let (is_valid_y_coord, X) = FieldElement::sqrt_ratio_i(&u, &v);
+
+        (is_valid_y_coord, X, Y, Z)
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -358,6 +439,131 @@ impl CompressedEdwardsY {
 
         CompressedEdwardsY(tmp)
     }
+
+    /// Check whether these bytes are the canonical encoding of the
+    /// identity point, without decompressing.
+    ///
+    /// The canonical encoding of the identity \\((0, 1)\\) is the byte
+    /// `0x01` followed by 31 zero bytes.
+    ///
+    /// Note that `decompress()` also maps some non-canonical encodings
+    /// to the identity, namely the ones with the sign bit set or with
+    /// \\(y\\) encoded as \\(p + 1\\); these are **not** recognised here.
+    pub fn is_identity_encoding(&self) -> bool {
+        self.ct_eq(&CompressedEdwardsY::identity()).into()
+    }
+
+    /// Check whether these bytes and `other` encode the same
+    /// \\(y\\)-coordinate, i.e. the same point up to sign, without
+    /// decompressing.
+    ///
+    /// Since \\(-(x, y) = (-x, y)\\), the encodings of \\(P\\) and
+    /// \\(-P\\) differ only in the sign bit of \\(x\\), which is the high
+    /// bit (`0x80`) of the last byte.  This compares all 256 bits with
+    /// that one masked off, in constant time.
+    ///
+    /// The \\(y\\)-coordinates are compared as bytes, not as field
+    /// elements, so a non-canonical encoding of \\(y\\) (as an integer
+    /// \\(\geq p\\)) does not equal its canonical encoding.
+    pub fn eq_ignore_sign(&self, other: &CompressedEdwardsY) -> bool {
+        let mut a = self.0;
+        let mut b = other.0;
+        a[31] &= 0x7f;
+        b[31] &= 0x7f;
+        a.ct_eq(&b).into()
+    }
+
+    /// Check whether these bytes decompress to a point whose
+    /// compression gives back the same bytes, i.e. whether they are the
+    /// canonical encoding of a point.
+    ///
+    /// This is `false` if `decompress()` fails, and also for the two
+    /// kinds of encodings which `decompress()` accepts but are not
+    /// canonical: a \\(y\\)-coordinate which is not reduced modulo
+    /// \\(p\\), and a set sign bit when \\(x = 0\\).
+    pub fn is_canonical_decodable(&self) -> bool {
+        match self.decompress() {
+            Some(point) => point.compress() == *self,
+            None => false,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl CompressedEdwardsY {
+    /// Read a `CompressedEdwardsY` from exactly 32 bytes of `reader`,
+    /// checking that it decompresses to a curve point.
+    ///
+    /// If `strict` is `true`, the bytes must moreover be the canonical
+    /// encoding of that point, i.e. recompressing it must give the same
+    /// bytes back.  This rejects \\(y\\)-coordinates which are not
+    /// reduced modulo \\(p\\) and a set sign bit with \\(x = 0\\).
+    ///
+    /// # Errors
+    ///
+    /// Any error from `read_exact` is passed through; an
+    /// [`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData)
+    /// error is returned if the bytes are not a valid (or, when `strict`
+    /// is set, canonical) point encoding.
+    pub fn read_from<R: ::std::io::Read>(reader: &mut R, strict: bool) -> ::std::io::Result<CompressedEdwardsY> {
+        use std::io::{Error, ErrorKind};
+
+        let mut bytes = [0u8; 32];
+        reader.read_exact(&mut bytes)?;
+        let compressed = CompressedEdwardsY(bytes);
+
+        let point = compressed.decompress().ok_or_else(|| {
+            Error::new(ErrorKind::InvalidData, "invalid Edwards point encoding")
+        })?;
+        if strict && point.compress() != compressed {
+            return Err(Error::new(ErrorKind::InvalidData, "non-canonical Edwards point encoding"));
+        }
+
+        Ok(compressed)
+    }
+
+    /// Write the 32 bytes of this `CompressedEdwardsY` to `writer`.
+    pub fn write_to<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl CompressedEdwardsY {
+    /// Decode a `CompressedEdwardsY` from 64 hex digits.
+    ///
+    /// Like `from_slice`, this does not check that the bytes are a valid
+    /// point encoding; that happens in `decompress()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DecodingError` if `s` is not 64 hex digits.
+    pub fn from_hex(s: &str) -> Result<CompressedEdwardsY, ::encoding::DecodingError> {
+        ::encoding::decode_hex(s).map(CompressedEdwardsY)
+    }
+
+    /// Encode this `CompressedEdwardsY` as lowercase hex.
+    pub fn to_hex(&self) -> String {
+        ::encoding::encode_hex(self.as_bytes())
+    }
+
+    /// Decode a `CompressedEdwardsY` from padded base64.
+    ///
+    /// Like `from_slice`, this does not check that the bytes are a valid
+    /// point encoding; that happens in `decompress()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DecodingError` if `s` is not a 44-character padded
+    /// base64 string.
+    pub fn from_base64(s: &str) -> Result<CompressedEdwardsY, ::encoding::DecodingError> {
+        ::encoding::decode_base64(s).map(CompressedEdwardsY)
+    }
+
+    /// Encode this `CompressedEdwardsY` as padded base64.
+    pub fn to_base64(&self) -> String {
+        ::encoding::encode_base64(self.as_bytes())
+    }
 }
 
 impl Identity for EdwardsPoint {
@@ -427,6 +633,27 @@ impl ConditionallySelectable for EdwardsPoint {
     }
 }
 
+impl EdwardsPoint {
+    /// Select `points[index]` in constant time.
+    ///
+    /// Every point in `points` is read, and the one at `index` is
+    /// chosen with a conditional assignment, so neither the running
+    /// time nor the memory access pattern depends on `index`.  They do
+    /// depend on the length of `points`, which is assumed to be public.
+    ///
+    /// # Return
+    ///
+    /// The point at position `index`, or the identity if `index` is out
+    /// of range.
+    pub fn ct_select(points: &[EdwardsPoint], index: u64) -> EdwardsPoint {
+        let mut selected = EdwardsPoint::identity();
+        for (i, P) in points.iter().enumerate() {
+            selected.conditional_assign(P, (i as u64).ct_eq(&index));
+        }
+        selected
+    }
+}
+
 // ------------------------------------------------------------------------
 // Equality
 // ------------------------------------------------------------------------
@@ -514,16 +741,239 @@ impl EdwardsPoint {
         MontgomeryPoint(u.to_bytes())
     }
 
+    /// Convert this `EdwardsPoint` to affine \\((u, v)\\) coordinates on
+    /// the Montgomery form of the curve, \\(v\^2 = u\^3 + Au\^2 + u\\),
+    /// returning the encodings of \\(u\\) and \\(v\\).
+    ///
+    /// This is the birational map of [RFC 7748][rfc7748]:
+    /// $$
+    /// (u, v) = \left( \frac{1 + y}{1 - y}, \sqrt{-486664} \frac{u}{x} \right),
+    /// $$
+    /// with the square root chosen so that the Ed25519 basepoint maps to
+    /// the X25519 basepoint \\((9, v)\\).  Unlike `to_montgomery`, this
+    /// keeps the \\(v\\) coordinate, and with it the sign of the point.
+    /// The point \\((0, -1)\\) of order 2 maps to \\((0, 0)\\).
+    ///
+    /// # Return
+    ///
+    /// - `Some((u, v))` with the canonical little-endian encodings of the
+    ///   coordinates;
+    /// - `None` if `self` is the identity, which maps to the point at
+    ///   infinity on the Montgomery curve.
+    ///
+    /// This is constant-time, except that the return value reveals
+    /// whether `self` is the identity.
+    ///
+    /// [rfc7748]: https://tools.ietf.org/html/rfc7748#section-4.1
+    pub fn to_montgomery_affine(&self) -> Option<([u8; 32], [u8; 32])> {
+        // We have u = (Z+Y)/(Z-Y) and v = c*u/x = c*(Z+Y)*Z/((Z-Y)*X),
+        // which we compute with a single inversion.  For (0,-1), X = 0
+        // and 0.invert() = 0, so both coordinates are zero.
+        //
+        // Both coordinates are computed even for the identity, so that
+        // only the final choice of return value depends on the point.
+        let U = &self.Z + &self.Y;
+        let W = &self.Z - &self.Y;
+        let is_identity = W.is_zero();
+
+        let recip = (&W * &self.X).invert();
+        let u = &(&U * &self.X) * &recip;
+        let v = &(&(&constants::SQRT_MINUS_APLUS2 * &U) * &self.Z) * &recip;
+        let coordinates = (u.to_bytes(), v.to_bytes());
+
+        if is_identity.into() {
+            None
+        } else {
+            Some(coordinates)
+        }
+    }
+
     /// Compress this point to `CompressedEdwardsY` format.
     pub fn compress(&self) -> CompressedEdwardsY {
+        let mut s = [0u8; 32];
+        self.compress_ct_into(&mut s);
+        CompressedEdwardsY(s)
+    }
+
+    /// Compress this point to `CompressedEdwardsY` format, writing the
+    /// 32-byte encoding into `out`.
+    ///
+    /// This is the same encoding as `compress`, which is also
+    /// constant-time: the inversion is a fixed exponentiation, and the
+    /// sign bit is set without branching.  Writing into a buffer owned
+    /// by the caller avoids an intermediate copy, for instance when
+    /// the encoding is part of a larger message or signature.
+    pub fn compress_ct_into(&self, out: &mut [u8; 32]) {
         let recip = self.Z.invert();
         let x = &self.X * &recip;
         let y = &self.Y * &recip;
-        let mut s: [u8; 32];
 
-        s = y.to_bytes();
-        s[31] ^= x.is_negative().unwrap_u8() << 7;
-        CompressedEdwardsY(s)
+        *out = y.to_bytes();
+        out[31] ^= x.is_negative().unwrap_u8() << 7;
+    }
+
+    /// Feed a vector of points into `hasher` as a single framed item,
+    /// for absorbing it into a Fiat-Shamir transcript.
+    ///
+    /// The frame is the total length of the encodings, \\( 32n \\) for
+    /// \\( n \\) points, as an 8-byte little-endian `u64`, followed by
+    /// the 32-byte compressed encodings of the points in slice order, as
+    /// in `Scalar::hash_many`.
+    ///
+    /// Each point is compressed separately, at the cost of one field
+    /// inversion per point.
+    pub fn hash_many<D: Digest>(points: &[EdwardsPoint], hasher: &mut D) {
+        hasher.update(((32 * points.len()) as u64).to_le_bytes());
+        for P in points {
+            hasher.update(P.compress().as_bytes());
+        }
+    }
+
+    /// Check that compressing this point and decompressing the result
+    /// gives back the same point.
+    ///
+    /// This always holds for a valid point; it is a self-check of the
+    /// coordinate representation, for fuzzing and conformance tests.
+    pub fn is_canonical_roundtrip(&self) -> bool {
+        match self.compress().decompress() {
+            Some(point) => point == *self,
+            None => false,
+        }
+    }
+
+    /// Compress this point to `CompressedEdwardsY` format, and also
+    /// return whether it is the identity, in constant time.
+    ///
+    /// The identity is the only point whose encoding is that of
+    /// \\((0, 1)\\), so the flag is a comparison of the encoding against
+    /// a constant, which is much cheaper than a separate `is_identity`.
+    pub fn compress_with_flags(&self) -> (CompressedEdwardsY, Choice) {
+        let compressed = self.compress();
+        let is_identity = compressed.0.ct_eq(&CompressedEdwardsY::identity().0);
+        (compressed, is_identity)
+    }
+
+    /// Compress a batch of points, appending their 32-byte
+    /// `CompressedEdwardsY` encodings to `out`, in order.
+    ///
+    /// This uses a single batched inversion for all of the points, and
+    /// writes the encodings directly into one contiguous buffer, which
+    /// is convenient for serialization.
+    #[cfg(feature = "alloc")]
+    pub fn compress_batch_flat(points: &[EdwardsPoint], out: &mut Vec<u8>) {
+        let mut recips: Vec<FieldElement> = points.iter().map(|P| P.Z).collect();
+        FieldElement::batch_invert(&mut recips);
+
+        out.reserve(32 * points.len());
+        for (P, recip) in points.iter().zip(recips.iter()) {
+            let x = &P.X * recip;
+            let y = &P.Y * recip;
+            let mut s = y.to_bytes();
+            s[31] ^= x.is_negative().unwrap_u8() << 7;
+            out.extend_from_slice(&s);
+        }
+    }
+
+    /// Decompress a contiguous buffer of 32-byte `CompressedEdwardsY`
+    /// encodings, as produced by `compress_batch_flat`.
+    ///
+    /// # Return
+    ///
+    /// - `Some(points)` if the length of `bytes` is a multiple of 32
+    ///   and every encoding is valid;
+    /// - `None` otherwise.
+    #[cfg(feature = "alloc")]
+    pub fn decompress_batch_flat(bytes: &[u8]) -> Option<Vec<EdwardsPoint>> {
+        let chunks = bytes.chunks_exact(32);
+        if !chunks.remainder().is_empty() {
+            return None;
+        }
+
+        chunks
+            .map(|chunk| CompressedEdwardsY::from_slice(chunk).decompress())
+            .collect()
+    }
+
+    /// Encode this point in the uncompressed 64-byte format, consisting
+    /// of the canonical little-endian encodings of the affine
+    /// \\(x\\)- and \\(y\\)-coordinates, in that order.
+    pub fn to_bytes_uncompressed(&self) -> [u8; 64] {
+        let recip = self.Z.invert();
+        let x = &self.X * &recip;
+        let y = &self.Y * &recip;
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&x.to_bytes());
+        bytes[32..].copy_from_slice(&y.to_bytes());
+        bytes
+    }
+
+    /// Decode a point from the uncompressed 64-byte format produced by
+    /// `to_bytes_uncompressed`.
+    ///
+    /// Like `CompressedEdwardsY::decompress`, this does not check that
+    /// the point lies in the prime-order subgroup.
+    ///
+    /// # Return
+    ///
+    /// - `Some(P)` if both coordinates are canonically encoded and
+    ///   \\((x, y)\\) is a point on the curve;
+    /// - `None` otherwise.
+    pub fn from_bytes_uncompressed(bytes: &[u8; 64]) -> Option<EdwardsPoint> {
+        let mut x_bytes = [0u8; 32];
+        let mut y_bytes = [0u8; 32];
+        x_bytes.copy_from_slice(&bytes[..32]);
+        y_bytes.copy_from_slice(&bytes[32..]);
+
+        let x = FieldElement::from_bytes(&x_bytes);
+        let y = FieldElement::from_bytes(&y_bytes);
+        let is_canonical = x.to_bytes().ct_eq(&x_bytes) & y.to_bytes().ct_eq(&y_bytes);
+
+        // Check the curve equation -x^2 + y^2 = 1 + d x^2 y^2
+        let xx = x.square();
+        let yy = y.square();
+        let lhs = &yy - &xx;
+        let rhs = &FieldElement::one() + &(&constants::EDWARDS_D * &(&xx * &yy));
+        let is_on_curve = lhs.ct_eq(&rhs);
+
+        if (is_canonical & is_on_curve).unwrap_u8() != 1u8 { return None; }
+
+        Some(EdwardsPoint{ X: x, Y: y, Z: FieldElement::one(), T: &x * &y })
+    }
+
+    /// Return the sign of this point, i.e. the low bit of the
+    /// canonical encoding of its affine \\(x\\)-coordinate.
+    ///
+    /// This is the same bit that `compress()` stores in the high bit of
+    /// the last byte of the `CompressedEdwardsY` encoding.
+    pub fn sign(&self) -> u8 {
+        let x = &self.X * &self.Z.invert();
+
+        x.is_negative().unwrap_u8()
+    }
+
+    /// Compute a 64-bit key for this point, suitable for use in a
+    /// memoization cache or other hash-based lookup structure.
+    ///
+    /// The key is the 64-bit FNV-1a hash of the compressed encoding.
+    /// Since the compressed encoding is canonical, equal points always
+    /// have equal keys, and the key is stable across platforms and
+    /// releases.
+    ///
+    /// # Warning
+    ///
+    /// This is **not** a cryptographic hash, and it is **not** computed
+    /// in constant time.  It should only be used for cache and hash-map
+    /// keys of public points, not for hashing into a transcript or for
+    /// deriving secrets.
+    pub fn cache_key(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x00000100000001b3;
+
+        self.compress()
+            .as_bytes()
+            .iter()
+            .fold(FNV_OFFSET_BASIS, |h, &b| (h ^ b as u64).wrapping_mul(FNV_PRIME))
     }
 
     /// Perform hashing to the group using the Elligator2 map
@@ -535,6 +985,24 @@ impl EdwardsPoint {
     {
         let mut hash = D::new();
         hash.update(bytes);
+        EdwardsPoint::from_hash(hash)
+    }
+
+    /// Construct an `EdwardsPoint` from an existing `Digest` instance,
+    /// using the Elligator2 map and clearing the cofactor.
+    ///
+    /// Use this instead of `hash_from_bytes` if it is more convenient
+    /// to stream data into the `Digest` than to pass a single byte
+    /// slice.
+    ///
+    /// Any `Digest` with a 64-byte output can be used, e.g. SHA-512,
+    /// SHA3-512 or BLAKE2b-512, as enforced by the `OutputSize = U64`
+    /// bound.  Only the first 32 bytes of the output are mapped to the
+    /// curve, so that this agrees with `hash_from_bytes`.
+    pub fn from_hash<D>(hash: D) -> EdwardsPoint
+    where
+        D: Digest<OutputSize = U64>,
+    {
         let h = hash.finalize();
         let mut res = [0u8; 32];
         res.copy_from_slice(&h[..32]);
@@ -550,6 +1018,27 @@ impl EdwardsPoint {
             .expect("Montgomery conversion to Edwards point in Elligator failed")
             .mul_by_cofactor()
     }
+
+    /// Sample a random torsion-free, non-identity `EdwardsPoint`, for
+    /// testing.
+    ///
+    /// The point is computed as \\( sB \\) for a random nonzero scalar
+    /// \\( s \\), where \\( B \\) is the Ed25519 basepoint, so it lies
+    /// in the prime-order subgroup.  Since the sampler knows its
+    /// discrete logarithm, it must **not** be used to derive generators,
+    /// e.g. for commitments; use `hash_from_bytes` for that instead.
+    ///
+    /// This is unlike `RistrettoPoint::random`, whose output has an
+    /// unknown discrete logarithm.
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> EdwardsPoint {
+        loop {
+            let s = Scalar::random(rng);
+            // s is zero with probability 1/l, but check anyway.
+            if s != Scalar::zero() {
+                return &constants::ED25519_BASEPOINT_TABLE * &s;
+            }
+        }
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -593,6 +1082,138 @@ impl<'a, 'b> Sub<&'b EdwardsPoint> for &'a EdwardsPoint {
 
 define_sub_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint, Output = EdwardsPoint);
 
+impl EdwardsPoint {
+    /// Compute both \\( P + Q \\) and \\( P - Q \\), where \\( P \\) is
+    /// `self` and \\( Q \\) is `other`, as in an FFT-style butterfly.
+    ///
+    /// This converts `other` to the Niels model once, and shares the
+    /// products which do not depend on the sign of \\( Q \\), so it is
+    /// cheaper than computing the sum and difference separately.
+    ///
+    /// # Return
+    ///
+    /// The pair `(P + Q, P - Q)`.
+    pub fn add_sub(&self, other: &EdwardsPoint) -> (EdwardsPoint, EdwardsPoint) {
+        let (sum, difference) = self.add_sub_projective_niels(&other.to_projective_niels());
+        (sum.to_extended(), difference.to_extended())
+    }
+}
+
+/// An `EdwardsPoint` cached in the projective Niels model, for repeated
+/// additions.
+///
+/// Adding an `EdwardsPoint` to another one first converts it to this
+/// form; converting once with `EdwardsNielsPoint::from` and adding the
+/// result saves doing that conversion on every addition.
+// This wraps the backend type so that the curve models can stay
+// internal.
+#[derive(Copy, Clone, Debug)]
+pub struct EdwardsNielsPoint(pub(crate) ProjectiveNielsPoint);
+
+/// An `EdwardsPoint` cached in the affine Niels model, for repeated
+/// additions.
+///
+/// Additions with this form are one field multiplication cheaper than
+/// with an `EdwardsNielsPoint`, but the conversion needs a field
+/// inversion, so it only pays off for points which are added many
+/// times.
+#[derive(Copy, Clone, Debug)]
+pub struct EdwardsAffineNielsPoint(pub(crate) AffineNielsPoint);
+
+impl From<&EdwardsPoint> for EdwardsNielsPoint {
+    fn from(P: &EdwardsPoint) -> EdwardsNielsPoint {
+        EdwardsNielsPoint(P.to_projective_niels())
+    }
+}
+
+impl From<&EdwardsPoint> for EdwardsAffineNielsPoint {
+    fn from(P: &EdwardsPoint) -> EdwardsAffineNielsPoint {
+        EdwardsAffineNielsPoint(P.to_affine_niels())
+    }
+}
+
+impl Neg for &EdwardsNielsPoint {
+    type Output = EdwardsNielsPoint;
+    fn neg(self) -> EdwardsNielsPoint {
+        EdwardsNielsPoint(-&self.0)
+    }
+}
+
+impl<'b> Add<&'b EdwardsNielsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: &'b EdwardsNielsPoint) -> EdwardsPoint {
+        (self + &other.0).to_extended()
+    }
+}
+
+define_add_variants!(LHS = EdwardsPoint, RHS = EdwardsNielsPoint, Output = EdwardsPoint);
+
+impl<'b> AddAssign<&'b EdwardsNielsPoint> for EdwardsPoint {
+    fn add_assign(&mut self, _rhs: &'b EdwardsNielsPoint) {
+        *self = (self as &EdwardsPoint) + _rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsNielsPoint);
+
+impl<'b> Sub<&'b EdwardsNielsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn sub(self, other: &'b EdwardsNielsPoint) -> EdwardsPoint {
+        (self - &other.0).to_extended()
+    }
+}
+
+define_sub_variants!(LHS = EdwardsPoint, RHS = EdwardsNielsPoint, Output = EdwardsPoint);
+
+impl<'b> SubAssign<&'b EdwardsNielsPoint> for EdwardsPoint {
+    fn sub_assign(&mut self, _rhs: &'b EdwardsNielsPoint) {
+        *self = (self as &EdwardsPoint) - _rhs;
+    }
+}
+
+define_sub_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsNielsPoint);
+
+impl Neg for &EdwardsAffineNielsPoint {
+    type Output = EdwardsAffineNielsPoint;
+    fn neg(self) -> EdwardsAffineNielsPoint {
+        EdwardsAffineNielsPoint(-&self.0)
+    }
+}
+
+impl<'b> Add<&'b EdwardsAffineNielsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: &'b EdwardsAffineNielsPoint) -> EdwardsPoint {
+        (self + &other.0).to_extended()
+    }
+}
+
+define_add_variants!(LHS = EdwardsPoint, RHS = EdwardsAffineNielsPoint, Output = EdwardsPoint);
+
+impl<'b> AddAssign<&'b EdwardsAffineNielsPoint> for EdwardsPoint {
+    fn add_assign(&mut self, _rhs: &'b EdwardsAffineNielsPoint) {
+        *self = (self as &EdwardsPoint) + _rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsAffineNielsPoint);
+
+impl<'b> Sub<&'b EdwardsAffineNielsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn sub(self, other: &'b EdwardsAffineNielsPoint) -> EdwardsPoint {
+        (self - &other.0).to_extended()
+    }
+}
+
+define_sub_variants!(LHS = EdwardsPoint, RHS = EdwardsAffineNielsPoint, Output = EdwardsPoint);
+
+impl<'b> SubAssign<&'b EdwardsAffineNielsPoint> for EdwardsPoint {
+    fn sub_assign(&mut self, _rhs: &'b EdwardsAffineNielsPoint) {
+        *self = (self as &EdwardsPoint) - _rhs;
+    }
+}
+
+define_sub_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsAffineNielsPoint);
+
 impl<'b> SubAssign<&'b EdwardsPoint> for EdwardsPoint {
     fn sub_assign(&mut self, _rhs: &'b EdwardsPoint) {
         *self = (self as &EdwardsPoint) - _rhs;
@@ -618,6 +1239,9 @@ where
 // Negation
 // ------------------------------------------------------------------------
 
+/// Negation, together with `ConditionallySelectable`, gives
+/// `EdwardsPoint` a constant-time `subtle::ConditionallyNegatable`
+/// implementation through `subtle`'s blanket impl.
 impl<'a> Neg for &'a EdwardsPoint {
     type Output = EdwardsPoint;
 
@@ -678,18 +1302,116 @@ impl<'a, 'b> Mul<&'b EdwardsPoint> for &'a Scalar {
     }
 }
 
-// ------------------------------------------------------------------------
-// Multiscalar Multiplication impls
-// ------------------------------------------------------------------------
+impl EdwardsPoint {
+    /// Compute `scalar * self` in constant time.
+    ///
+    /// This is the same as `self * scalar`, under a name which makes
+    /// the guarantee explicit: the running time and memory access
+    /// pattern depend neither on `scalar` nor on `self`.  Use this for
+    /// secret scalars, e.g. when signing or in key exchange.
+    pub fn mul_constant_time(&self, scalar: &Scalar) -> EdwardsPoint {
+        scalar_mul::variable_base::mul(self, scalar)
+    }
 
-// These use the iterator's size hint and the target settings to
-// forward to a specific backend implementation.
+    /// Compute `scalar * self` in variable time.
+    ///
+    /// This is faster than `mul_constant_time`, but its running time
+    /// depends on `scalar`, so it must only be used when `scalar` is
+    /// public, e.g. when verifying signatures.
+    pub fn vartime_mul(&self, scalar: &Scalar) -> EdwardsPoint {
+        scalar_mul::vartime_double_base::mul(scalar, self, &Scalar::zero())
+    }
 
-#[cfg(feature = "alloc")]
-impl MultiscalarMul for EdwardsPoint {
-    type Point = EdwardsPoint;
+    /// Compute \\( s\^{-1} P \\), where \\(P\\) is `self` and \\(s\\) is
+    /// `scalar`, without exposing the inverse \\( s\^{-1} \\).
+    ///
+    /// This function is constant time.  The intermediate inverse is
+    /// zeroized before returning.
+    ///
+    /// # Return
+    ///
+    /// * `Some(P * scalar.invert())` if `scalar` is nonzero;
+    ///
+    /// * `None` if `scalar` is zero.
+    pub fn mul_by_inverse(&self, scalar: &Scalar) -> CtOption<EdwardsPoint> {
+        let is_nonzero = !scalar.ct_eq(&Scalar::zero());
 
-    fn multiscalar_mul<I, J>(scalars: I, points: J) -> EdwardsPoint
+        let mut inverse = scalar.invert();
+        let result = self * inverse;
+        inverse.zeroize();
+
+        CtOption::new(result, is_nonzero)
+    }
+
+    /// Compute \\( A - cB \\), where \\(A\\) is `self`.
+    ///
+    /// The negation is folded into the scalar, so this costs one
+    /// scalar multiplication by \\(-c\\) and one addition, rather than a
+    /// multiplication followed by a point negation and subtraction.
+    ///
+    /// This function is constant time.
+    pub fn sub_scalar_mul(&self, c: &Scalar, B: &EdwardsPoint) -> EdwardsPoint {
+        self + scalar_mul::variable_base::mul(B, &-c)
+    }
+
+    /// Compute the products \\( a\_i P\_i \\) of each pair
+    /// \\( (a\_i, P\_i) \\) in `pairs`.
+    ///
+    /// The results are independent: the output has one point per input
+    /// pair, in the same order, and they are **not** summed.  Use
+    /// `multiscalar_mul` to compute \\( \sum\_i a\_i P\_i \\) instead.
+    ///
+    /// This is only a convenience: it is the same as multiplying each
+    /// pair separately, and shares no work or memory between the
+    /// products.  (Each constant-time multiplication keeps its lookup
+    /// table on the stack, so there is no scratch allocation to reuse.)
+    ///
+    /// This function is constant time.
+    #[cfg(feature = "alloc")]
+    pub fn batch_mul(pairs: &[(Scalar, EdwardsPoint)]) -> Vec<EdwardsPoint> {
+        pairs
+            .iter()
+            .map(|(a, P)| scalar_mul::variable_base::mul(P, a))
+            .collect()
+    }
+
+    /// Compute the multiscalar sum \\( \sum\_j a\_{ij} P\_{ij} \\) of
+    /// each group \\( i \\) of pairs \\( (a\_{ij}, P\_{ij}) \\) in
+    /// `groups`, in variable time.
+    ///
+    /// The output has one point per group, in the same order, and each
+    /// output is the same as `vartime_multiscalar_mul` on its group; an
+    /// empty group gives the identity.  As there, groups of fewer than
+    /// 190 pairs are computed by Straus' method and larger ones by
+    /// Pippenger's.  The buffers that Straus' method needs for the NAFs
+    /// and lookup tables are allocated once and reused across groups,
+    /// rather than allocated for each group.  This only saves the
+    /// allocations, which are cheap next to the point arithmetic, so the
+    /// main benefit is avoiding allocator churn with many small groups.
+    ///
+    /// This function is **not** constant time, and is meant for public
+    /// scalars, as in verification.
+    #[cfg(feature = "alloc")]
+    pub fn vartime_grouped_multiscalar(groups: &[&[(Scalar, EdwardsPoint)]]) -> Vec<EdwardsPoint> {
+        scalar_mul::straus::Straus::vartime_grouped_multiscalar_mul(
+            groups,
+            STRAUS_PIPPENGER_THRESHOLD,
+        )
+    }
+}
+
+// ------------------------------------------------------------------------
+// Multiscalar Multiplication impls
+// ------------------------------------------------------------------------
+
+// These use the iterator's size hint and the target settings to
+// forward to a specific backend implementation.
+
+#[cfg(feature = "alloc")]
+impl MultiscalarMul for EdwardsPoint {
+    type Point = EdwardsPoint;
+
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> EdwardsPoint
     where
         I: IntoIterator,
         I::Item: Borrow<Scalar>,
@@ -744,7 +1466,7 @@ impl VartimeMultiscalarMul for EdwardsPoint {
         // Use this as the hint to decide which algorithm to use.
         let size = s_lo;
 
-        if size < 190 {
+        if size < STRAUS_PIPPENGER_THRESHOLD {
             scalar_mul::straus::Straus::optional_multiscalar_mul(scalars, points)
         } else {
             scalar_mul::pippenger::Pippenger::optional_multiscalar_mul(scalars, points)
@@ -752,7 +1474,77 @@ impl VartimeMultiscalarMul for EdwardsPoint {
     }
 }
 
+/// Operation counts of a variable-time multiscalar multiplication, as
+/// returned by `EdwardsPoint::vartime_multiscalar_mul_with_stats`.
+///
+/// The counts include the group operations used to build lookup
+/// tables, but not conversions between curve models.
+#[cfg(feature = "stats")]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct MultiscalarStats {
+    /// The number of point doublings.
+    pub doublings: usize,
+    /// The number of point additions and subtractions.
+    pub additions: usize,
+    /// The window width, in bits, of the scalar recoding.
+    pub window: usize,
+}
+
+#[cfg(feature = "stats")]
+impl EdwardsPoint {
+    /// Compute \\( \sum\_i s\_i P\_i \\) in variable time, like
+    /// `vartime_multiscalar_mul`, and also return the number of group
+    /// operations that were used.
+    ///
+    /// This chooses between Straus and Pippenger exactly as
+    /// `vartime_multiscalar_mul` does, so the counts can be used to
+    /// tune batch sizes.
+    ///
+    /// # Panics
+    ///
+    /// If the number of scalars and points differ.
+    pub fn vartime_multiscalar_mul_with_stats<I, J>(
+        scalars: I,
+        points: J,
+    ) -> (EdwardsPoint, MultiscalarStats)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        let scalars: Vec<Scalar> = scalars.into_iter().map(|s| *s.borrow()).collect();
+        let points: Vec<EdwardsPoint> = points.into_iter().map(|P| *P.borrow()).collect();
+        assert_eq!(scalars.len(), points.len());
+
+        let mut recorder = scalar_mul::StatsRecorder::default();
+        let points = points.into_iter().map(Some);
+        let result = if scalars.len() < STRAUS_PIPPENGER_THRESHOLD {
+            scalar_mul::straus::Straus::optional_multiscalar_mul_recorded(
+                &scalars,
+                points,
+                &mut recorder,
+            )
+        } else {
+            scalar_mul::pippenger::Pippenger::optional_multiscalar_mul_recorded(
+                &scalars,
+                points,
+                &mut recorder,
+            )
+        };
+
+        (result.expect("all points are present"), recorder.stats)
+    }
+}
+
 /// Precomputation for variable-time multiscalar multiplication with `EdwardsPoint`s.
+///
+/// The non-adjacent forms computed from the scalars during a
+/// multiplication are zeroized afterwards, which limits the
+/// scalar-derived data left in memory.  This does not make the
+/// multiplication constant-time: its running time and memory access
+/// pattern still depend on the scalars, so it should not be used with
+/// secret scalars.
 // This wraps the inner implementation in a facade type so that we can
 // decouple stability of the inner type from the stability of the
 // outer type.
@@ -789,6 +1581,79 @@ impl VartimePrecomputedMultiscalarMul for VartimeEdwardsPrecomputation {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl VartimeEdwardsPrecomputation {
+    /// Return the number of static points in this precomputation.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return `true` if this precomputation has no static points.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return the number of heap bytes used by the lookup tables of a
+    /// precomputation for `num_points` static points.
+    ///
+    /// Each static point gets a table of its 64 odd multiples
+    /// \\(B\_i, 3B\_i, \\ldots, 127B\_i\\).  With the serial backends
+    /// these are stored as affine Niels points of 120 bytes each, for
+    /// 7680 bytes per static point; the AVX2 and IFMA backends store
+    /// larger, vectorized points.
+    ///
+    /// This can be compared against a memory budget before calling
+    /// `new`, for instance to fall back to a non-precomputed
+    /// multiscalar multiplication when the tables would be too large.
+    pub fn footprint_bytes(num_points: usize) -> usize {
+        scalar_mul::precomputed_straus::VartimePrecomputedStraus::footprint_bytes(num_points)
+    }
+
+    /// Return the number of heap bytes allocated for the lookup tables
+    /// of this precomputation.
+    ///
+    /// This is `footprint_bytes(self.len())`, unless extra capacity was
+    /// left over from combining precomputations with `append`.
+    pub fn footprint(&self) -> usize {
+        self.0.footprint()
+    }
+
+    /// Append the static points of `other` to this precomputation,
+    /// reusing its lookup tables.
+    ///
+    /// Static scalars for the combined precomputation are given in the
+    /// order of the points of `self`, followed by those of `other`.
+    pub fn append(&mut self, other: Self) {
+        self.0.append(other.0);
+    }
+
+    /// Combine several precomputations into one, in order, reusing their
+    /// lookup tables.
+    ///
+    /// This is equivalent to, but cheaper than, calling `new` on the
+    /// concatenation of their static points.
+    pub fn concat<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        Self(scalar_mul::precomputed_straus::VartimePrecomputedStraus::concat(
+            parts.into_iter().map(|part| part.0),
+        ))
+    }
+
+    /// Iterate over the static points \\(B\_i\\) that were supplied to
+    /// `new`, reconstructed from the precomputed tables.
+    pub fn points<'a>(&'a self) -> impl Iterator<Item = EdwardsPoint> + 'a {
+        self.0.points()
+    }
+}
+
+#[cfg(feature = "const_generics")]
+mod precomputed_straus_array;
+
+#[cfg(feature = "const_generics")]
+pub use self::precomputed_straus_array::PrecomputedStrausArray;
+
 impl EdwardsPoint {
     /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
     pub fn vartime_double_scalar_mul_basepoint(
@@ -798,6 +1663,436 @@ impl EdwardsPoint {
     ) -> EdwardsPoint {
         scalar_mul::vartime_double_base::mul(a, A, b)
     }
+
+    /// Compute \\(aA + bB\\) in variable time.
+    ///
+    /// This gives the same result as `vartime_multiscalar_mul` with two
+    /// terms, as needed to verify a Schnorr signature, but keeps the
+    /// lookup tables on the stack instead of collecting the inputs into
+    /// heap-allocated buffers, so it is available without `alloc`.
+    pub fn vartime_multiscalar_mul_2(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+        B: &EdwardsPoint,
+    ) -> EdwardsPoint {
+        scalar_mul::inline_straus::vartime_multiscalar_mul(&[*a, *b], &[*A, *B])
+    }
+
+    /// Compute \\(aA + bB + cC\\) in variable time.
+    ///
+    /// Like `vartime_multiscalar_mul_2`, this gives the same result as
+    /// `vartime_multiscalar_mul`, without allocating.
+    pub fn vartime_multiscalar_mul_3(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+        B: &EdwardsPoint,
+        c: &Scalar,
+        C: &EdwardsPoint,
+    ) -> EdwardsPoint {
+        scalar_mul::inline_straus::vartime_multiscalar_mul(&[*a, *b, *c], &[*A, *B, *C])
+    }
+
+    /// Check the cofactored verification equation of Ed25519,
+    /// \\( [8]([s]B - R - [k]A) = 0 \\), in variable time.
+    ///
+    /// Here \\( R \\) is the decompressed commitment of a signature,
+    /// \\( s \\) its response, \\( A \\) the decompressed public key,
+    /// \\( k \\) the challenge and \\( B \\) the basepoint.  This is the
+    /// check specified by [RFC 8032][rfc8032] and required by
+    /// [ZIP 215][zip215]: it ignores any torsion components of
+    /// \\( R \\) and \\( A \\), so that all implementations agree on
+    /// which signatures are valid, and it is the equation to use with
+    /// batch verification.  Decoding \\( R \\), \\( A \\) and
+    /// \\( s \\), and computing \\( k \\), are left to the caller.
+    ///
+    /// [rfc8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.7
+    /// [zip215]: https://zips.z.cash/zip-0215
+    pub fn verify_cofactored(
+        R: &EdwardsPoint,
+        A: &EdwardsPoint,
+        B: &EdwardsPoint,
+        k: &Scalar,
+        s: &Scalar,
+    ) -> bool {
+        EdwardsPoint::vartime_multiscalar_mul_3(s, B, k, &-A, &Scalar::one(), &-R)
+            .mul_by_cofactor()
+            .is_identity()
+    }
+
+    /// Check the cofactorless verification equation of Ed25519,
+    /// \\( [s]B - [k]A = R \\), in variable time.
+    ///
+    /// The arguments are as for `verify_cofactored`.  This stricter
+    /// check, which is what most single-signature verifiers implement,
+    /// rejects some signatures which the cofactored equation accepts:
+    /// those where \\( R \\) or \\( A \\) have a torsion component that
+    /// does not cancel out.  So it should not be mixed with the
+    /// cofactored equation, or with batch verification, in a system
+    /// where verifiers must agree.
+    ///
+    /// This compares points, not encodings: a caller which also wants
+    /// to reject non-canonical encodings of \\( R \\) should check them
+    /// separately, e.g. with `CompressedEdwardsY::is_canonical_decodable`.
+    pub fn verify_cofactorless(
+        R: &EdwardsPoint,
+        A: &EdwardsPoint,
+        B: &EdwardsPoint,
+        k: &Scalar,
+        s: &Scalar,
+    ) -> bool {
+        EdwardsPoint::vartime_multiscalar_mul_2(s, B, k, &-A) == *R
+    }
+
+    /// Compute \\( c\_1 P\_1 + \cdots + c\_n P\_n \\) in variable time,
+    /// for small signed integer coefficients \\( c\_i \\).
+    ///
+    /// This is much cheaper than converting each coefficient to a
+    /// `Scalar` and calling `vartime_multiscalar_mul`, since it only
+    /// needs as many doublings as there are bits in an `i64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `coeffs` and `points` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn vartime_multiscalar_mul_small(coeffs: &[i64], points: &[EdwardsPoint]) -> EdwardsPoint {
+        assert_eq!(coeffs.len(), points.len());
+
+        scalar_mul::straus::vartime_small_multiscalar_mul(coeffs, points)
+    }
+
+    /// Check whether this point is equal to any of `set`, in constant
+    /// time.
+    ///
+    /// Every entry of `set` is compared with `ct_eq`, and the results
+    /// are combined with `|`, so the running time depends only on the
+    /// length of `set`, and not on whether or where a match is found.
+    /// Each comparison costs four field multiplications, so the cost is
+    /// linear in the size of the set; this is meant for small sets,
+    /// such as allowlists of a few points.
+    pub fn ct_is_in(&self, set: &[EdwardsPoint]) -> Choice {
+        set.iter().fold(Choice::from(0), |found, P| found | self.ct_eq(P))
+    }
+
+    /// Compute `self + other` if `choice` is set, and `self` otherwise,
+    /// in constant time.
+    ///
+    /// The addition is always performed, and the result is then chosen
+    /// with `conditional_select`, so neither the running time nor the
+    /// memory access pattern depend on `choice`.
+    pub fn conditional_add(&self, other: &EdwardsPoint, choice: Choice) -> EdwardsPoint {
+        let sum = self + other;
+        EdwardsPoint::conditional_select(self, &sum, choice)
+    }
+
+    /// Negate each of `points` in place.
+    ///
+    /// This flips the sign of the \\(X\\) and \\(T\\) coordinates of each
+    /// point, giving the same result as `*P = -*P` in a loop, without
+    /// creating a new point for each.
+    pub fn negate_batch(points: &mut [EdwardsPoint]) {
+        for P in points.iter_mut() {
+            P.X = -&P.X;
+            P.T = -&P.T;
+        }
+    }
+
+    /// Double each of `points`, returning \\(2P\_i\\) in order.
+    ///
+    /// This gives the same result as `P + P` for each point, with the
+    /// cheaper dedicated doubling formulas, applied to each point in
+    /// turn.
+    #[cfg(feature = "alloc")]
+    pub fn double_batch(points: &[EdwardsPoint]) -> Vec<EdwardsPoint> {
+        points.iter().map(EdwardsPoint::double).collect()
+    }
+
+    /// Check that no two of `points` are equal.
+    ///
+    /// This is meant for validating a freshly derived set of generators
+    /// during setup, since a collision between generators would break
+    /// the binding property of commitments built from them.  Correctly
+    /// derived generators never collide, so production code should not
+    /// need this, but it catches mis-derived generators early.
+    ///
+    /// Points are compared by their compressed encodings.  This
+    /// function is variable-time, and should only be used on public
+    /// points.  Since it is a debugging aid, it is only available with
+    /// the `debug_all_distinct` feature.
+    #[cfg(all(feature = "alloc", any(test, feature = "debug_all_distinct")))]
+    pub fn all_distinct(points: &[EdwardsPoint]) -> bool {
+        let mut encodings: Vec<[u8; 32]> = points.iter().map(|P| P.compress().to_bytes()).collect();
+        encodings.sort_unstable();
+
+        encodings.windows(2).all(|pair| pair[0] != pair[1])
+    }
+
+    /// Like `VartimeMultiscalarMul::optional_multiscalar_mul`, but always
+    /// uses Pippenger's algorithm with a digit width of `w` bits, rather
+    /// than choosing the algorithm and width from the number of inputs.
+    ///
+    /// The default choice is tuned for full-size scalars; this allows
+    /// overriding it for unusual inputs.  For instance, a smaller window
+    /// can be faster for many points with short scalars, since every
+    /// window costs \\( 2\^w \\) bucket additions even when its digits
+    /// are all zero.
+    ///
+    /// This function is variable-time.
+    ///
+    /// # Panics
+    ///
+    /// If `w` is not between 4 and 8, inclusive.
+    #[cfg(feature = "alloc")]
+    pub fn optional_multiscalar_mul_with_window<I, J>(
+        scalars: I,
+        points: J,
+        w: usize,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        scalar_mul::pippenger::Pippenger::optional_multiscalar_mul_with_window(scalars, points, w)
+    }
+
+    /// Check, in constant time, whether
+    /// \\( c\_1 P\_1 + \cdots + c\_n P\_n \\) equals `target`.
+    ///
+    /// The sum is compared with `target` in projective coordinates, so
+    /// neither of them is compressed or normalized.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_mul_eq<I, J>(scalars: I, points: J, target: &EdwardsPoint) -> Choice
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        EdwardsPoint::multiscalar_mul(scalars, points).ct_eq(target)
+    }
+
+    /// Check, in variable time, whether
+    /// \\( c\_1 P\_1 + \cdots + c\_n P\_n \\) equals `target`.
+    ///
+    /// This is the typical final step of verifying a proof, and should
+    /// only be used on public data.  As for `multiscalar_mul_eq`, the
+    /// comparison is done in projective coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn vartime_multiscalar_mul_eq<I, J>(scalars: I, points: J, target: &EdwardsPoint) -> bool
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        EdwardsPoint::vartime_multiscalar_mul(scalars, points) == *target
+    }
+
+    /// Compute the part \\( \sum\_{i \in \mathrm{range}} c\_i P\_i \\) of the
+    /// constant-time multiscalar multiplication of `scalars` and `points`.
+    ///
+    /// This lets callers split a large multiscalar multiplication into
+    /// work units for their own thread pool: for ranges which partition
+    /// `0..scalars.len()`, the sum of the partial results equals
+    /// `EdwardsPoint::multiscalar_mul(scalars, points)`.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths, or if `range`
+    /// is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// # use curve25519_dalek::traits::MultiscalarMul;
+    /// # use curve25519_dalek::constants;
+    /// let scalars: Vec<Scalar> = (1..=10u64).map(Scalar::from).collect();
+    /// let points: Vec<EdwardsPoint> = (1..=10u64)
+    ///     .map(|i| &Scalar::from(i) * &constants::ED25519_BASEPOINT_TABLE)
+    ///     .collect();
+    ///
+    /// let total = EdwardsPoint::multiscalar_mul_partial(&scalars, &points, 0..4)
+    ///     + EdwardsPoint::multiscalar_mul_partial(&scalars, &points, 4..10);
+    /// assert_eq!(total, EdwardsPoint::multiscalar_mul(&scalars, &points));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_mul_partial(
+        scalars: &[Scalar],
+        points: &[EdwardsPoint],
+        range: Range<usize>,
+    ) -> EdwardsPoint {
+        assert_eq!(scalars.len(), points.len());
+        EdwardsPoint::multiscalar_mul(&scalars[range.clone()], &points[range])
+    }
+
+    /// Compute
+    /// \\( (a\_1 P\_1 + \cdots + a\_n P\_n) - (b\_1 Q\_1 + \cdots + b\_m Q\_m) \\)
+    /// in constant time, with a single multiscalar multiplication.
+    ///
+    /// The \\( b\_j \\) are negated and appended to the \\( a\_i \\), so
+    /// the doublings are shared between both sums.  Checking whether the
+    /// result is the identity tells whether the two sums are equal.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars_a` and `points_a`, or `scalars_b` and `points_b`,
+    /// have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_difference<I, J, K, L>(
+        scalars_a: I,
+        points_a: J,
+        scalars_b: K,
+        points_b: L,
+    ) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+        K: IntoIterator,
+        K::Item: Borrow<Scalar>,
+        L: IntoIterator,
+        L::Item: Borrow<EdwardsPoint>,
+    {
+        let (scalars, points) = difference_inputs(scalars_a, points_a, scalars_b, points_b);
+        EdwardsPoint::multiscalar_mul(scalars, points)
+    }
+
+    /// Compute
+    /// \\( (a\_1 P\_1 + \cdots + a\_n P\_n) - (b\_1 Q\_1 + \cdots + b\_m Q\_m) \\)
+    /// in variable time, with a single multiscalar multiplication.
+    ///
+    /// This is the variable-time version of `multiscalar_difference`,
+    /// for public inputs.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars_a` and `points_a`, or `scalars_b` and `points_b`,
+    /// have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn vartime_multiscalar_difference<I, J, K, L>(
+        scalars_a: I,
+        points_a: J,
+        scalars_b: K,
+        points_b: L,
+    ) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+        K: IntoIterator,
+        K::Item: Borrow<Scalar>,
+        L: IntoIterator,
+        L::Item: Borrow<EdwardsPoint>,
+    {
+        let (scalars, points) = difference_inputs(scalars_a, points_a, scalars_b, points_b);
+        EdwardsPoint::vartime_multiscalar_mul(scalars, points)
+    }
+}
+
+/// A decompressed Ed25519 public key \\(A\\), with precomputed
+/// multiples of \\(-A\\) for checking the verification equation.
+///
+/// Verifying a signature \\((R, s)\\) with challenge \\(k\\) computes
+/// \\([s]B - [k]A\\), which `vartime_double_scalar_mul_basepoint`
+/// does by first building a table of odd multiples of \\(-A\\).  A
+/// `VerificationKey` builds that table once, so that repeated
+/// verifications against the same key skip the decompression, the
+/// negation and the precomputation.
+#[derive(Copy, Clone, Debug)]
+pub struct VerificationKey {
+    compressed: CompressedEdwardsY,
+    point: EdwardsPoint,
+    minus_A: scalar_mul::vartime_double_base::VartimeDoubleBaseTable,
+}
+
+impl VerificationKey {
+    /// Decompress the public key `compressed` and precompute its table.
+    ///
+    /// Returns `None` if `compressed` does not decompress.  Like
+    /// `decompress`, this does not check that the key is torsion-free
+    /// or canonically encoded.
+    pub fn new(compressed: CompressedEdwardsY) -> Option<VerificationKey> {
+        let point = compressed.decompress()?;
+        Some(VerificationKey {
+            compressed,
+            point,
+            minus_A: scalar_mul::vartime_double_base::VartimeDoubleBaseTable::new(&-point),
+        })
+    }
+
+    /// Return the compressed encoding this key was created from.
+    pub fn compressed(&self) -> &CompressedEdwardsY {
+        &self.compressed
+    }
+
+    /// Return the public key \\(A\\) as an `EdwardsPoint`.
+    pub fn point(&self) -> &EdwardsPoint {
+        &self.point
+    }
+
+    /// Compute \\([s]B - [k]A\\) in variable time, where \\(B\\) is the
+    /// Ed25519 basepoint, using the precomputed table.
+    ///
+    /// This gives the same result as
+    /// `EdwardsPoint::vartime_double_scalar_mul_basepoint(k, &-A, s)`.
+    pub fn vartime_double_scalar_mul_basepoint(&self, k: &Scalar, s: &Scalar) -> EdwardsPoint {
+        self.minus_A.mul(k, s)
+    }
+
+    /// Check the cofactorless verification equation
+    /// \\([s]B - [k]A = R\\), by recomputing \\(R\\) and comparing its
+    /// compressed encoding to `R`, in variable time.
+    ///
+    /// The challenge \\(k\\) is computed by the caller, for Ed25519 as
+    /// the reduction of \\(H(R \| A \| M)\\); the bytes of \\(A\\) should
+    /// be those returned by `compressed`.
+    pub fn vartime_verify(&self, R: &CompressedEdwardsY, k: &Scalar, s: &Scalar) -> bool {
+        self.vartime_double_scalar_mul_basepoint(k, s).compress() == *R
+    }
+}
+
+/// Concatenate the inputs of `multiscalar_difference`, negating the
+/// scalars of the subtracted sum.
+#[cfg(feature = "alloc")]
+fn difference_inputs<I, J, K, L>(
+    scalars_a: I,
+    points_a: J,
+    scalars_b: K,
+    points_b: L,
+) -> (Vec<Scalar>, Vec<EdwardsPoint>)
+where
+    I: IntoIterator,
+    I::Item: Borrow<Scalar>,
+    J: IntoIterator,
+    J::Item: Borrow<EdwardsPoint>,
+    K: IntoIterator,
+    K::Item: Borrow<Scalar>,
+    L: IntoIterator,
+    L::Item: Borrow<EdwardsPoint>,
+{
+    let mut scalars: Vec<Scalar> = scalars_a.into_iter().map(|a| *a.borrow()).collect();
+    let mut points: Vec<EdwardsPoint> = points_a.into_iter().map(|P| *P.borrow()).collect();
+    assert_eq!(scalars.len(), points.len());
+
+    scalars.extend(scalars_b.into_iter().map(|b| -b.borrow()));
+    points.extend(points_b.into_iter().map(|Q| *Q.borrow()));
+    assert_eq!(scalars.len(), points.len());
+
+    (scalars, points)
 }
 
 macro_rules! impl_basepoint_table {
@@ -917,6 +2212,29 @@ impl BasepointTable for $name {
     }
 }
 
+impl $name {
+    /// Check that each row of this table holds the expected multiples
+    /// of its basepoint, returning `false` if any entry differs.
+    ///
+    /// This is meant as a self-check against memory corruption, for
+    /// instance periodically in a long-running process or after loading
+    /// a serialized table.  It recomputes the table row by row from
+    /// `basepoint()`, so it is as slow as `create`, whose cost roughly
+    /// doubles with each step in the radix: on a modern x86-64 machine
+    /// it is about 2.5ms for radix 32 (four dozen variable-base scalar
+    /// multiplications), 5ms for radix 64, 10ms for radix 128 and 20ms
+    /// for radix 256.  To also check which point the table is for,
+    /// compare `basepoint()` with it.
+    pub fn verify(&self) -> bool {
+        let mut P = self.basepoint();
+        self.0.iter().all(|row| {
+            let expected_row = $table::from(&P);
+            P = P.mul_by_pow_2($radix + $radix);
+            row.0.iter().zip(expected_row.0.iter()).all(|(a, b)| a == b)
+        })
+    }
+}
+
 impl<'a, 'b> Mul<&'b Scalar> for &'a $name {
     type Output = $point;
 
@@ -992,6 +2310,7 @@ impl_basepoint_table! {Name = EdwardsBasepointTableRadix256, LookupTable = Looku
 /// When \\(w = 8\\), we can't fit \\(carry \cdot 2^{w}\\) into an `i8`, so we
 /// add the carry bit onto an additional coefficient.
 #[derive(Clone)]
+#[cfg_attr(feature = "ffi", repr(transparent))]
 pub struct EdwardsBasepointTable(pub(crate) [LookupTable<AffineNielsPoint>; 32]);
 
 impl EdwardsBasepointTable {
@@ -1053,6 +2372,195 @@ impl EdwardsBasepointTable {
     pub fn basepoint(&self) -> EdwardsPoint {
         (&EdwardsPoint::identity() + &self.0[0].select(1)).to_extended()
     }
+
+    /// Compute \\(aB\\), as `basepoint_mul`, with the scalar additively
+    /// blinded by randomness from `rng`.
+    ///
+    /// The scalar is split as \\(a = a\_1 + a\_2\\), for a uniformly random
+    /// \\(a\_1\\), and the result is computed as \\(a\_1 B + a\_2 B\\).  This
+    /// is the same point as \\(aB\\), but the digits
+    /// selected from the table in each multiplication are independent
+    /// of \\(a\\), so that an attacker who observes the computation
+    /// through power or electromagnetic side channels, or who averages
+    /// many observations, learns nothing about \\(a\\) from either one
+    /// alone.  The multiplication is constant-time in either case; the
+    /// blinding only hardens it against physical leakage, at the cost of
+    /// a second multiplication and of sampling a scalar.
+    ///
+    /// Since \\(a\_1 + a\_2\\) is only equal to \\(a\\) modulo \\(\ell\\),
+    /// the basepoint must be in the prime-order subgroup, as the Ed25519
+    /// basepoint is.  For a basepoint with a torsion component, the
+    /// result may differ from \\(aB\\) by a torsion point.
+    pub fn mul_blinded<R: RngCore + CryptoRng>(&self, scalar: &Scalar, rng: &mut R) -> EdwardsPoint {
+        let mut a_1 = Scalar::random(rng);
+        let mut a_2 = scalar - a_1;
+        let result = self.basepoint_mul(&a_1) + self.basepoint_mul(&a_2);
+
+        a_1.zeroize();
+        a_2.zeroize();
+        result
+    }
+
+    /// Compute \\(aB - bC\\), where \\(B\\) is this table's basepoint and
+    /// \\(C\\) is the basepoint of `other`.
+    ///
+    /// This walks the radix-16 digits of both scalars together, as
+    /// `basepoint_mul` does for one, so the four doublings of the
+    /// accumulator are shared between the two tables; the digits of
+    /// \\(b\\) are subtracted rather than added, so the result is exact
+    /// even if the basepoints have a torsion component.
+    ///
+    /// Like `basepoint_mul`, this is constant-time in both scalars.
+    pub fn diff_mul(&self, a: &Scalar, other: &EdwardsBasepointTable, b: &Scalar) -> EdwardsPoint {
+        self.mul_2_radix_16(&a.to_radix_16(), other, &b.to_radix_16(), true)
+    }
+
+    /// Compute \\(aB + bC\\), or \\(aB - bC\\) if `subtract` is set, from
+    /// the radix-16 digits of \\(a\\) and \\(b\\), where \\(B\\) is this
+    /// table's basepoint and \\(C\\) is the basepoint of `other`.
+    ///
+    /// As in `basepoint_mul`, this adds the odd digits, multiplies by
+    /// 16, and adds the even digits, for both tables at once.  It is
+    /// constant-time in the digits, but not in `subtract`.
+    pub(crate) fn mul_2_radix_16(
+        &self,
+        a: &[i8; 64],
+        other: &EdwardsBasepointTable,
+        b: &[i8; 64],
+        subtract: bool,
+    ) -> EdwardsPoint {
+        let (B, C) = (&self.0, &other.0);
+        let add_digits = |P: &EdwardsPoint, i: usize| {
+            let P = (P + &B[i / 2].select(a[i])).to_extended();
+            let Q = C[i / 2].select(b[i]);
+            if subtract {
+                (&P - &Q).to_extended()
+            } else {
+                (&P + &Q).to_extended()
+            }
+        };
+
+        let mut P = EdwardsPoint::identity();
+        for i in (0..64).filter(|x| x % 2 == 1) {
+            P = add_digits(&P, i);
+        }
+
+        P = P.mul_by_pow_2(4);
+
+        for i in (0..64).filter(|x| x % 2 == 0) {
+            P = add_digits(&P, i);
+        }
+
+        P
+    }
+
+    /// Check that each row of this table holds the expected multiples
+    /// of its basepoint: that entry \\(j\\) of row \\(i\\) is
+    /// \\((j + 1) \cdot 16\^{2i} B\\) for \\(j = 0, \ldots, 7\\).  Returns
+    /// `false` if any entry differs.
+    ///
+    /// This is meant as a self-check against memory corruption, for
+    /// instance periodically in a long-running process or after loading
+    /// a serialized table.  It recomputes the table row by row from
+    /// `basepoint()`, so it is as slow as `create`: about a millisecond
+    /// on a modern x86-64 machine, or two dozen variable-base scalar
+    /// multiplications.  To also check which point the table is for,
+    /// compare `basepoint()` with it.
+    pub fn verify(&self) -> bool {
+        let mut P = self.basepoint();
+        self.0.iter().all(|row| {
+            let expected_row = LookupTable::from(&P);
+            P = P.mul_by_pow_2(8);
+            row.0.iter().zip(expected_row.0.iter()).all(|(a, b)| a == b)
+        })
+    }
+
+    /// Iterate over the first `count` consecutive multiples of the
+    /// basepoint \\(B\\), i.e., \\(1B, 2B, \ldots, \mathrm{count} \cdot B\\).
+    ///
+    /// Each multiple is computed from the previous one with a single
+    /// point addition, which is much cheaper than computing each one
+    /// with `basepoint_mul`.  This only yields consecutive small
+    /// multiples; use `basepoint_mul` for arbitrary ones.
+    pub fn multiples(&self, count: usize) -> impl Iterator<Item = EdwardsPoint> {
+        let B = self.0[0].select(1);
+        let mut P = EdwardsPoint::identity();
+
+        (0..count).map(move |_| {
+            P = (&P + &B).to_extended();
+            P
+        })
+    }
+}
+
+/// A limb of a field element, as stored by the selected backend: a
+/// `u64` holding 51 bits of the value on 64-bit backends.
+///
+/// Only available with the `ffi` feature; see
+/// `EdwardsBasepointTable::as_flat_field_limbs`.
+#[cfg(all(feature = "ffi", any(feature = "u64_backend", feature = "fiat_u64_backend")))]
+pub type FieldLimb = u64;
+
+/// A limb of a field element, as stored by the selected backend: a
+/// `u32` holding 26 or 25 bits of the value on 32-bit backends.
+///
+/// Only available with the `ffi` feature; see
+/// `EdwardsBasepointTable::as_flat_field_limbs`.
+#[cfg(all(feature = "ffi", any(feature = "u32_backend", feature = "fiat_u32_backend")))]
+pub type FieldLimb = u32;
+
+#[cfg(feature = "ffi")]
+impl EdwardsBasepointTable {
+    /// View this table as a flat array of field limbs, for reading it
+    /// from C or another language.
+    ///
+    /// # Layout
+    ///
+    /// The table has 32 rows of 8 points, where entry \\(j\\) of row
+    /// \\(i\\) is \\((j + 1) \cdot 16\^{2i} B\\).  The rows are stored in
+    /// order, and the points of each row in order, so that point
+    /// \\((i, j)\\) starts at limb `(8 * i + j) * 3 * NLIMBS`.  Each point
+    /// consists of three field elements, in the order
+    /// \\(y + x\\), \\(y - x\\) and \\(2dxy\\), of its affine
+    /// coordinates.  With `NLIMBS` limbs per field element, that is
+    /// `32 * 8 * 3 * NLIMBS` limbs, the same size as the table.
+    ///
+    /// Each field element is stored as `NLIMBS` limbs, least significant
+    /// first; each limb is a native-endian integer of type `FieldLimb`,
+    /// and the value of the element is their weighted sum modulo
+    /// \\(p\\):
+    ///
+    /// * on the `u64_backend` (and so the `simd_backend`) and the
+    ///   `fiat_u64_backend`, `NLIMBS = 5` and `FieldLimb = u64`, and the
+    ///   value is \\(\sum\_k l\_k 2\^{51k}\\);
+    /// * on the `u32_backend` and the `fiat_u32_backend`, `NLIMBS = 10`
+    ///   and `FieldLimb = u32`, and the value is
+    ///   \\(\sum\_k l\_k 2\^{\lceil 25.5k \rceil}\\), i.e. the limbs hold
+    ///   26 and 25 bits alternately.
+    ///
+    /// The limbs are not necessarily fully reduced: a limb may be a
+    /// little larger than its nominal width, and the weighted sum may
+    /// exceed \\(p\\).
+    ///
+    /// # Warning
+    ///
+    /// The layout follows the internal representation of the backend
+    /// selected at compile time, so the C side must be built for the
+    /// same backend and byte order.
+    pub fn as_flat_field_limbs(&self) -> &[FieldLimb] {
+        const LEN: usize =
+            ::core::mem::size_of::<EdwardsBasepointTable>() / ::core::mem::size_of::<FieldLimb>();
+
+        // SAFETY: with the `ffi` feature, `EdwardsBasepointTable`,
+        // `LookupTable` and the field element types are
+        // `repr(transparent)` over arrays, and `AffineNielsPoint` is a
+        // `repr(C)` struct of three field elements, which has no padding.
+        // So `self` is an array of `LEN` limbs, aligned for `FieldLimb`,
+        // and the returned slice borrows it for as long as `self`.
+        unsafe {
+            ::core::slice::from_raw_parts(self as *const EdwardsBasepointTable as *const FieldLimb, LEN)
+        }
+    }
 }
 
 impl<'a, 'b> Mul<&'b Scalar> for &'a EdwardsBasepointTable {
@@ -1080,6 +2588,31 @@ impl<'a, 'b> Mul<&'a EdwardsBasepointTable> for &'b Scalar {
 // END legacy 3.x series code for backwards compatibility with BasepointTable trait
 // -------------------------------------------------------------------------------------
 
+/// Compute \\(aB\\), where \\(B\\) is the basepoint of `table`.
+///
+/// This is the same as `table * scalar`, as a free function, so that code
+/// which is generic over the basepoint can take a
+/// `&EdwardsBasepointTable` whether it is the global
+/// `ED25519_BASEPOINT_TABLE` or one created for another point.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::constants::{ED25519_BASEPOINT_POINT, ED25519_BASEPOINT_TABLE};
+/// use curve25519_dalek::edwards::{mul_base_with_table, EdwardsBasepointTable};
+/// use curve25519_dalek::scalar::Scalar;
+///
+/// let a = Scalar::from(42u64);
+/// assert_eq!(mul_base_with_table(&ED25519_BASEPOINT_TABLE, &a), ED25519_BASEPOINT_POINT * a);
+///
+/// let H = ED25519_BASEPOINT_POINT * Scalar::from(7u64);
+/// let table = EdwardsBasepointTable::create(&H);
+/// assert_eq!(mul_base_with_table(&table, &a), H * a);
+/// ```
+pub fn mul_base_with_table(table: &EdwardsBasepointTable, scalar: &Scalar) -> EdwardsPoint {
+    table.basepoint_mul(scalar)
+}
+
 macro_rules! impl_basepoint_table_conversions {
     (LHS = $lhs:ty, RHS = $rhs:ty) => {
         impl<'a> From<&'a $lhs> for $rhs {
@@ -1110,6 +2643,179 @@ impl_basepoint_table_conversions!{LHS = EdwardsBasepointTableRadix64, RHS = Edwa
 
 impl_basepoint_table_conversions!{LHS = EdwardsBasepointTableRadix128, RHS = EdwardsBasepointTableRadix256}
 
+/// A precomputed table for constant-time fixed-base scalar
+/// multiplication by the comb method.
+///
+/// For a window of \\(w\\) bits, the 256 bits of a scalar are split
+/// into \\(w\\) rows of \\(d = \lceil 256 / w \rceil\\) bits, and the
+/// table holds all \\(2\^w - 1\\) nonzero sums of the points
+/// \\(2\^{id} B\\) for \\(0 \leq i < w\\).  A multiplication then takes
+/// \\(d\\) doublings and \\(d\\) additions, each addition selecting one
+/// column of scalar bits from the table in constant time.
+///
+/// Compared with the `EdwardsBasepointTable` types, the comb table is
+/// much smaller but does more doublings.  On an x86-64 machine with
+/// the `u64_backend`, one multiplication took:
+///
+/// | table                      | entries | size     | time    |
+/// |----------------------------|---------|----------|---------|
+/// | comb, 4 bits               | 15      | 1.8 KiB  | 24.4µs  |
+/// | comb, 5 bits               | 31      | 3.6 KiB  | 23.3µs  |
+/// | comb, 6 bits               | 63      | 7.4 KiB  | 29.5µs  |
+/// | comb, 7 bits               | 127     | 14.9 KiB | 35.6µs  |
+/// | comb, 8 bits               | 255     | 29.9 KiB | 64.2µs  |
+/// | `EdwardsBasepointTable`    | 256     | 30 KiB   | 14.3µs  |
+///
+/// So the radix-16 table is faster at every window size, and the comb
+/// table is the better choice only when memory is tight: a 5-bit comb
+/// runs at about 60% of the radix-16 speed in an eighth of the space.
+/// Beyond 5 bits, the constant-time scan of the larger table costs more
+/// than the doublings it saves.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct EdwardsCombTable {
+    window_bits: usize,
+    spacing: usize,
+    /// `table[j - 1]` holds the sum of the points `2^(i * spacing) B` for
+    /// each bit `i` set in `j`.
+    table: Vec<AffineNielsPoint>,
+}
+
+#[cfg(feature = "alloc")]
+impl EdwardsCombTable {
+    /// Create a comb table for `basepoint`, with a window of
+    /// `window_bits` bits.
+    ///
+    /// The table holds \\(2\^w - 1\\) points.
+    ///
+    /// # Panics
+    ///
+    /// If `window_bits` is not between 1 and 8, inclusive.
+    pub fn create(basepoint: &EdwardsPoint, window_bits: usize) -> EdwardsCombTable {
+        assert!((1..=8).contains(&window_bits), "comb window must be between 1 and 8 bits");
+
+        let spacing = (256 + window_bits - 1) / window_bits;
+        let mut teeth = Vec::with_capacity(window_bits);
+        let mut P = *basepoint;
+        for i in 0..window_bits {
+            if i > 0 {
+                P = P.mul_by_pow_2(spacing as u32);
+            }
+            teeth.push(P);
+        }
+
+        // Build the sums for j = 1, 2, ..., by adding the tooth for the
+        // top bit of j to the already computed sum for the lower bits.
+        let mut sums: Vec<EdwardsPoint> = Vec::with_capacity(1 << window_bits);
+        sums.push(EdwardsPoint::identity());
+        for (i, tooth) in teeth.iter().enumerate() {
+            for j in 0..(1 << i) {
+                let sum = sums[j] + tooth;
+                sums.push(sum);
+            }
+        }
+
+        EdwardsCombTable {
+            window_bits,
+            spacing,
+            table: sums[1..].iter().map(|P| P.to_affine_niels()).collect(),
+        }
+    }
+
+    /// Get the basepoint for this table as an `EdwardsPoint`.
+    pub fn basepoint(&self) -> EdwardsPoint {
+        (&EdwardsPoint::identity() + &self.table[0]).to_extended()
+    }
+
+    /// Return the table entry for the column of bits `index`, in
+    /// constant time.
+    fn select(&self, index: usize) -> AffineNielsPoint {
+        let mut t = AffineNielsPoint::identity();
+        for (j, entry) in self.table.iter().enumerate() {
+            t.conditional_assign(entry, ((j + 1) as u16).ct_eq(&(index as u16)));
+        }
+        t
+    }
+
+    /// Compute \\(aB\\) for this table's basepoint \\(B\\), in constant time.
+    fn comb_mul(&self, scalar: &Scalar) -> EdwardsPoint {
+        let bytes = scalar.as_bytes();
+        let bit = |n: usize| -> usize {
+            if n < 256 {
+                ((bytes[n >> 3] >> (n & 7)) & 1) as usize
+            } else {
+                0
+            }
+        };
+
+        let mut P = EdwardsPoint::identity();
+        for k in (0..self.spacing).rev() {
+            let mut index = 0;
+            for i in 0..self.window_bits {
+                index |= bit(k + i * self.spacing) << i;
+            }
+            P = (&P.double() + &self.select(index)).to_extended();
+        }
+
+        P
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Mul<&Scalar> for &EdwardsCombTable {
+    type Output = EdwardsPoint;
+
+    /// Construct an `EdwardsPoint` from a `Scalar` \\(a\\) by
+    /// computing the multiple \\(aB\\) of this basepoint \\(B\\).
+    fn mul(self, scalar: &Scalar) -> EdwardsPoint {
+        self.comb_mul(scalar)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Mul<&EdwardsCombTable> for &Scalar {
+    type Output = EdwardsPoint;
+
+    /// Construct an `EdwardsPoint` from a `Scalar` \\(a\\) by
+    /// computing the multiple \\(aB\\) of this basepoint \\(B\\).
+    fn mul(self, table: &EdwardsCombTable) -> EdwardsPoint {
+        table * self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for EdwardsCombTable {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        f.debug_struct("EdwardsCombTable")
+            .field("window_bits", &self.window_bits)
+            .field("table", &self.table)
+            .finish()
+    }
+}
+
+/// The order of an `EdwardsPoint`, as computed by `EdwardsPoint::order`.
+///
+/// Only available with the `debug_point_order` feature.
+#[cfg(any(test, feature = "debug_point_order"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PointOrder {
+    /// The identity point.
+    One,
+    /// A point of order 2.
+    Two,
+    /// A point of order 4.
+    Four,
+    /// A point of order 8.
+    Eight,
+    /// A point of order \\( \ell \\), i.e., a point in the prime-order
+    /// subgroup other than the identity.
+    Prime,
+    /// A point of order \\( 2 \ell \\), \\( 4 \ell \\) or
+    /// \\( 8 \ell \\), i.e., with both a nonzero torsion component and a
+    /// nonzero prime-order component.
+    Composite,
+}
+
 impl EdwardsPoint {
     /// Multiply by the cofactor: return \\([8]P\\).
     pub fn mul_by_cofactor(&self) -> EdwardsPoint {
@@ -1184,6 +2890,67 @@ impl EdwardsPoint {
     pub fn is_torsion_free(&self) -> bool {
         (self * constants::BASEPOINT_ORDER).is_identity()
     }
+
+    /// Clear the cofactor of this point, and check whether it was already
+    /// torsion-free: return \\(([8]P, P \in \mathcal E[\ell])\\).
+    ///
+    /// This packages the usual sanitization of an untrusted point, e.g.
+    /// the result of a multiscalar multiplication over points which may
+    /// have a torsion component.  The first element is always in the
+    /// prime-order subgroup; note that it is \\([8]P\\), not \\(P\\) with
+    /// its torsion component removed, so protocols which clear the
+    /// cofactor must do so consistently.  The second element is the
+    /// result of `is_torsion_free`.
+    ///
+    /// The cost is that of `mul_by_cofactor`, three doublings, plus that
+    /// of `is_torsion_free`, a constant-time multiplication by
+    /// \\(\ell\\).  There is no cheaper exact test for a torsion
+    /// component; if only the cleared point is needed, use
+    /// `mul_by_cofactor` alone.
+    pub fn clear_and_check_torsion(&self) -> (EdwardsPoint, bool) {
+        (self.mul_by_cofactor(), self.is_torsion_free())
+    }
+
+    /// Compute the order of this point.
+    ///
+    /// The group of curve points is \\( \mathbb Z / 8 \times \mathbb Z / \ell \\),
+    /// so the order of a point is \\( d \\) or \\( d \ell \\) for some
+    /// \\( d \in \\{1, 2, 4, 8\\} \\).
+    ///
+    /// # Warning
+    ///
+    /// This function is variable-time and relatively slow.  It is
+    /// intended only for testing and debugging, e.g. to check the output
+    /// of hash-to-curve or decompression, and should not be used on
+    /// secret points.  For that reason it is only available with the
+    /// `debug_point_order` feature.
+    #[cfg(any(test, feature = "debug_point_order"))]
+    pub fn order(&self) -> PointOrder {
+        // The torsion component of self, scaled by an invertible factor
+        let torsion = self * constants::BASEPOINT_ORDER;
+        let torsion_order = if torsion.is_identity() {
+            1
+        } else if torsion.mul_by_pow_2(1).is_identity() {
+            2
+        } else if torsion.mul_by_pow_2(2).is_identity() {
+            4
+        } else {
+            8
+        };
+
+        if self.is_small_order() {
+            match torsion_order {
+                1 => PointOrder::One,
+                2 => PointOrder::Two,
+                4 => PointOrder::Four,
+                _ => PointOrder::Eight,
+            }
+        } else if torsion_order == 1 {
+            PointOrder::Prime
+        } else {
+            PointOrder::Composite
+        }
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -1291,6 +3058,508 @@ mod test {
         assert_eq!(minus_basepoint.T, -(&constants::ED25519_BASEPOINT_POINT.T));
     }
 
+    /// Test that `sign()` agrees with the sign bit set by `compress()`.
+    #[test]
+    fn sign_matches_compressed_sign_bit() {
+        let mut rng = rand::thread_rng();
+        let bp = constants::ED25519_BASEPOINT_POINT;
+
+        assert_eq!(bp.sign(), 0);
+        assert_eq!((-bp).sign(), 1);
+        assert_eq!(EdwardsPoint::identity().sign(), 0);
+
+        for _ in 0..16 {
+            let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+            assert_eq!(P.sign(), P.compress().as_bytes()[31] >> 7);
+            assert_eq!((-P).sign(), 1 - P.sign());
+        }
+    }
+
+    #[test]
+    fn cache_key_respects_equality() {
+        let bp = constants::ED25519_BASEPOINT_POINT;
+
+        // The same point in a different projective representation
+        let two = &FieldElement::one() + &FieldElement::one();
+        let bp_scaled = EdwardsPoint {
+            X: &bp.X * &two,
+            Y: &bp.Y * &two,
+            Z: &bp.Z * &two,
+            T: &bp.T * &two,
+        };
+
+        assert_eq!(bp.cache_key(), bp_scaled.cache_key());
+        assert_eq!(bp.cache_key(), (bp + EdwardsPoint::identity()).cache_key());
+        assert_ne!(bp.cache_key(), (-bp).cache_key());
+        assert_ne!(bp.cache_key(), EdwardsPoint::identity().cache_key());
+
+        // Pin the key so that it stays stable across releases.
+        assert_eq!(EdwardsPoint::identity().cache_key(), 0x07295d91aa94b524);
+    }
+
+    #[test]
+    fn uncompressed_bytes_roundtrip() {
+        let mut rng = rand::thread_rng();
+
+        let mut points = vec![EdwardsPoint::identity(), constants::ED25519_BASEPOINT_POINT];
+        points.extend_from_slice(&constants::EIGHT_TORSION);
+        points.push(&constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng));
+
+        for P in points.iter() {
+            let bytes = P.to_bytes_uncompressed();
+            let Q = EdwardsPoint::from_bytes_uncompressed(&bytes).unwrap();
+            assert_eq!(*P, Q);
+            assert!(Q.is_valid());
+        }
+
+        let mut identity_bytes = [0u8; 64];
+        identity_bytes[32] = 1;
+        assert_eq!(EdwardsPoint::identity().to_bytes_uncompressed()[..], identity_bytes[..]);
+
+        // The y-coordinate must agree with the compressed encoding
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let mut y = B.compress().to_bytes();
+        y[31] &= 0x7f;
+        assert_eq!(B.to_bytes_uncompressed()[32..], y[..]);
+    }
+
+    #[test]
+    fn uncompressed_bytes_rejects_invalid() {
+        // (0, 0) is not on the curve
+        assert!(EdwardsPoint::from_bytes_uncompressed(&[0u8; 64]).is_none());
+
+        // Flipping a coordinate bit leaves the curve
+        let mut bytes = constants::ED25519_BASEPOINT_POINT.to_bytes_uncompressed();
+        bytes[0] ^= 1;
+        assert!(EdwardsPoint::from_bytes_uncompressed(&bytes).is_none());
+
+        // Non-canonical encoding of y = 1 as p + 1, with x = 0
+        let mut bytes = [0u8; 64];
+        bytes[32] = 0xee;
+        for b in bytes[33..63].iter_mut() {
+            *b = 0xff;
+        }
+        bytes[63] = 0x7f;
+        assert!(EdwardsPoint::from_bytes_uncompressed(&bytes).is_none());
+    }
+
+    #[test]
+    fn compress_batch_flat_roundtrip() {
+        let mut rng = rand::thread_rng();
+
+        let mut points = vec![EdwardsPoint::identity(), constants::EIGHT_TORSION[3]];
+        for _ in 0..8 {
+            points.push(&constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng));
+        }
+
+        // Existing contents of the buffer are kept
+        let mut bytes = vec![0xaa];
+        EdwardsPoint::compress_batch_flat(&points, &mut bytes);
+        assert_eq!(bytes.len(), 1 + 32 * points.len());
+        assert_eq!(bytes[0], 0xaa);
+        for (P, chunk) in points.iter().zip(bytes[1..].chunks(32)) {
+            assert_eq!(P.compress().as_bytes()[..], chunk[..]);
+        }
+
+        assert_eq!(EdwardsPoint::decompress_batch_flat(&bytes[1..]), Some(points));
+
+        let mut empty = Vec::new();
+        EdwardsPoint::compress_batch_flat(&[], &mut empty);
+        assert!(empty.is_empty());
+        assert_eq!(EdwardsPoint::decompress_batch_flat(&[]), Some(Vec::new()));
+    }
+
+    #[test]
+    fn decompress_batch_flat_rejects_invalid() {
+        let mut bytes = Vec::new();
+        EdwardsPoint::compress_batch_flat(&[constants::ED25519_BASEPOINT_POINT; 2], &mut bytes);
+
+        // Truncated buffer
+        assert!(EdwardsPoint::decompress_batch_flat(&bytes[..63]).is_none());
+
+        // y = 2 is not the y-coordinate of a curve point
+        bytes[32..].copy_from_slice(&[0u8; 32]);
+        bytes[32] = 2;
+        assert!(EdwardsPoint::decompress_batch_flat(&bytes).is_none());
+    }
+
+    #[test]
+    fn decompress_vartime_matches_decompress() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..16 {
+            let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+            for encoding in [P.compress(), (-P).compress()].iter() {
+                let Q = encoding.decompress_vartime().unwrap();
+                assert_eq!(Q, encoding.decompress().unwrap());
+                assert_eq!(Q.compress(), *encoding);
+            }
+        }
+
+        // y = 2 is not the y-coordinate of a curve point
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(CompressedEdwardsY(bytes).decompress().is_none());
+        assert!(CompressedEdwardsY(bytes).decompress_vartime().is_none());
+    }
+
+    #[test]
+    fn decompress_then_mul_matches_two_steps() {
+        let mut rng = rand::thread_rng();
+
+        let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+        let a = Scalar::random(&mut rng);
+        let encoding = P.compress();
+
+        assert_eq!(encoding.decompress_then_mul(&a), Some(P * a));
+        assert_eq!(encoding.vartime_decompress_then_mul(&a), Some(P * a));
+
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(CompressedEdwardsY(bytes).decompress_then_mul(&a).is_none());
+        assert!(CompressedEdwardsY(bytes).vartime_decompress_then_mul(&a).is_none());
+    }
+
+    #[test]
+    fn is_identity_encoding() {
+        assert!(CompressedEdwardsY::identity().is_identity_encoding());
+        assert!(EdwardsPoint::identity().compress().is_identity_encoding());
+        assert!(!constants::ED25519_BASEPOINT_COMPRESSED.is_identity_encoding());
+        assert!(!CompressedEdwardsY([0u8; 32]).is_identity_encoding());
+
+        // The identity with the sign bit set decompresses to the
+        // identity, but is not the canonical encoding.
+        let mut minus_zero = [0u8; 32];
+        minus_zero[0] = 1;
+        minus_zero[31] = 0x80;
+        let minus_zero = CompressedEdwardsY(minus_zero);
+        assert!(minus_zero.decompress().unwrap().is_identity());
+        assert!(!minus_zero.is_identity_encoding());
+    }
+
+    #[test]
+    #[cfg(feature = "ffi")]
+    fn basepoint_table_flat_field_limbs() {
+        let table = &constants::ED25519_BASEPOINT_TABLE;
+        let limbs = table.as_flat_field_limbs();
+        let nlimbs = FieldElement::zero().0.len();
+        assert_eq!(limbs.len(), 32 * 8 * 3 * nlimbs);
+
+        for (i, row) in table.0.iter().enumerate() {
+            for (j, P) in row.0.iter().enumerate() {
+                let start = (8 * i + j) * 3 * nlimbs;
+                let point = &limbs[start..start + 3 * nlimbs];
+                assert_eq!(&point[..nlimbs], &P.y_plus_x.0[..]);
+                assert_eq!(&point[nlimbs..2 * nlimbs], &P.y_minus_x.0[..]);
+                assert_eq!(&point[2 * nlimbs..], &P.xy2d.0[..]);
+            }
+        }
+
+        // The first point is B, with y + x and y - x summing to 2y.
+        let mut y_plus_x = FieldElement::zero();
+        let mut y_minus_x = FieldElement::zero();
+        y_plus_x.0.copy_from_slice(&limbs[..nlimbs]);
+        y_minus_x.0.copy_from_slice(&limbs[nlimbs..2 * nlimbs]);
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let two_y = &(&B.Y + &B.Y) * &B.Z.invert();
+        assert_eq!(&y_plus_x + &y_minus_x, two_y);
+    }
+
+    #[test]
+    fn decompress_ct_matches_decompress() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            let compressed = EdwardsPoint::random(&mut rng).compress();
+            let P = compressed.decompress_ct();
+            assert!(bool::from(P.is_some()));
+            assert_eq!(P.unwrap(), compressed.decompress().unwrap());
+        }
+
+        // The identity with the sign bit set decompresses like with
+        // `decompress`, and y = 2 does not decompress.
+        let mut minus_zero = CompressedEdwardsY::identity().to_bytes();
+        minus_zero[31] |= 0x80;
+        assert!(CompressedEdwardsY(minus_zero).decompress_ct().unwrap().is_identity());
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(bool::from(CompressedEdwardsY(bytes).decompress_ct().is_none()));
+    }
+
+    #[test]
+    fn double_batch_matches_addition() {
+        let mut rng = rand::thread_rng();
+        let mut points: Vec<EdwardsPoint> = (0..7).map(|_| EdwardsPoint::random(&mut rng)).collect();
+        points.push(EdwardsPoint::identity());
+        points.push(constants::EIGHT_TORSION[4]);
+
+        let doubled = EdwardsPoint::double_batch(&points);
+        assert_eq!(doubled.len(), points.len());
+        for (D, P) in doubled.iter().zip(points.iter()) {
+            assert_eq!(*D, P + P);
+        }
+        assert!(EdwardsPoint::double_batch(&[]).is_empty());
+    }
+
+    /// Decode an Ed25519 signature and public key, and compute the
+    /// challenge \\( k = H(R \| A \| M) \\).
+    fn ed25519_verification_inputs(
+        public_key: &str,
+        signature: &str,
+        message: &[u8],
+    ) -> (EdwardsPoint, EdwardsPoint, Scalar, Scalar) {
+        use digest::Digest;
+
+        let public_key = hex::decode(public_key).unwrap();
+        let signature = hex::decode(signature).unwrap();
+        let A = CompressedEdwardsY::from_slice(&public_key).decompress().unwrap();
+        let R = CompressedEdwardsY::from_slice(&signature[..32]).decompress().unwrap();
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&signature[32..]);
+        let s = Scalar::from_canonical_bytes(s).unwrap();
+
+        let k = Scalar::from_hash(
+            sha2::Sha512::new().chain(&signature[..32]).chain(&public_key).chain(message),
+        );
+        (R, A, k, s)
+    }
+
+    #[test]
+    fn verify_rfc8032_vectors() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let vectors: [(&str, &str, &[u8]); 2] = [
+            (
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+                b"",
+            ),
+            (
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+                b"\x72",
+            ),
+        ];
+
+        for (public_key, signature, message) in vectors.iter() {
+            let (R, A, k, s) = ed25519_verification_inputs(public_key, signature, message);
+            assert!(EdwardsPoint::verify_cofactored(&R, &A, &B, &k, &s));
+            assert!(EdwardsPoint::verify_cofactorless(&R, &A, &B, &k, &s));
+
+            let (R, A, k, s) = ed25519_verification_inputs(public_key, signature, b"forged");
+            assert!(!EdwardsPoint::verify_cofactored(&R, &A, &B, &k, &s));
+            assert!(!EdwardsPoint::verify_cofactorless(&R, &A, &B, &k, &s));
+        }
+    }
+
+    #[test]
+    fn verify_small_order_edge_cases() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        // ZIP 215 accepts any signature with small-order A and R and
+        // s = 0; the cofactorless equation only does for some k.
+        let (T1, T3) = (constants::EIGHT_TORSION[1], constants::EIGHT_TORSION[3]);
+        for k in 0..8u64 {
+            let k = Scalar::from(k);
+            assert!(EdwardsPoint::verify_cofactored(&T3, &T1, &B, &k, &Scalar::zero()));
+            assert_eq!(
+                EdwardsPoint::verify_cofactorless(&T3, &T1, &B, &k, &Scalar::zero()),
+                -(k * T1) == T3
+            );
+        }
+
+        // A torsion component in R is ignored by the cofactored equation
+        // only.
+        let (a, r, k) = (Scalar::from(1234u64), Scalar::from(5678u64), Scalar::from(91011u64));
+        let A = B * a;
+        let s = r + k * a;
+        let R = B * r;
+        assert!(EdwardsPoint::verify_cofactorless(&R, &A, &B, &k, &s));
+        let R_torsion = R + T1;
+        assert!(EdwardsPoint::verify_cofactored(&R_torsion, &A, &B, &k, &s));
+        assert!(!EdwardsPoint::verify_cofactorless(&R_torsion, &A, &B, &k, &s));
+
+        // Likewise for A, since k is odd here.
+        let A_torsion = A + T1;
+        assert!(EdwardsPoint::verify_cofactored(&R, &A_torsion, &B, &k, &s));
+        assert!(!EdwardsPoint::verify_cofactorless(&R, &A_torsion, &B, &k, &s));
+    }
+
+    #[test]
+    fn verify_zip215_small_order_vectors() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        // The encodings of small-order points used by the ZIP 215 test
+        // vectors: the eight canonical ones, then six non-canonical
+        // ones, where y is not reduced or x = 0 has its sign bit set.
+        let encodings = [
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000080",
+            "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a",
+            "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac03fa",
+            "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc05",
+            "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc85",
+            "0100000000000000000000000000000000000000000000000000000000000080",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ];
+
+        // Each vector signs b"Zcash" with one of these as A and one as R,
+        // and s = 0.  ZIP 215 requires all 196 to be accepted.
+        for A_hex in encodings.iter() {
+            for R_hex in encodings.iter() {
+                let signature = format!("{}{}", R_hex, "00".repeat(32));
+                let (R, A, k, s) = ed25519_verification_inputs(A_hex, &signature, b"Zcash");
+                assert!(R.is_small_order() && A.is_small_order());
+                assert!(EdwardsPoint::verify_cofactored(&R, &A, &B, &k, &s));
+                assert_eq!(
+                    EdwardsPoint::verify_cofactorless(&R, &A, &B, &k, &s),
+                    -(k * A) == R
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ct_is_in() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let set: Vec<EdwardsPoint> = (1..=4u64).map(|i| B * Scalar::from(i)).collect();
+
+        for P in set.iter() {
+            assert!(bool::from(P.ct_is_in(&set)));
+            // Equality does not depend on the projective representation.
+            assert!(bool::from((P + EdwardsPoint::identity()).ct_is_in(&set)));
+        }
+        assert!(!bool::from((B * Scalar::from(5u64)).ct_is_in(&set)));
+        assert!(!bool::from(B.ct_is_in(&[])));
+    }
+
+    #[test]
+    fn basepoint_table_diff_mul() {
+        let mut rng = rand::thread_rng();
+        let B = &constants::ED25519_BASEPOINT_TABLE;
+        // A basepoint with a torsion component
+        let C_point = EdwardsPoint::random(&mut rng) + constants::EIGHT_TORSION[1];
+        let C = EdwardsBasepointTable::create(&C_point);
+
+        for _ in 0..8 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            assert_eq!(B.diff_mul(&a, &C, &b), B * &a - &C * &b);
+        }
+
+        let zero = Scalar::zero();
+        assert!(B.diff_mul(&zero, &C, &zero).is_identity());
+        assert_eq!(B.diff_mul(&zero, &C, &Scalar::one()), -C_point);
+        assert!(C.diff_mul(&A_SCALAR, &C, &A_SCALAR).is_identity());
+    }
+
+    #[test]
+    fn basepoint_table_mul_blinded() {
+        let mut rng = rand::thread_rng();
+        let B = &constants::ED25519_BASEPOINT_TABLE;
+        for _ in 0..4 {
+            let a = Scalar::random(&mut rng);
+            assert_eq!(B.mul_blinded(&a, &mut rng), B * &a);
+        }
+        assert!(B.mul_blinded(&Scalar::zero(), &mut rng).is_identity());
+    }
+
+    #[test]
+    fn clear_and_check_torsion() {
+        let P = constants::ED25519_BASEPOINT_POINT * Scalar::from(11u64);
+        assert_eq!(P.clear_and_check_torsion(), (P.mul_by_cofactor(), true));
+
+        for T in constants::EIGHT_TORSION[1..].iter() {
+            let (cleared, was_torsion_free) = (P + T).clear_and_check_torsion();
+            assert_eq!(cleared, P.mul_by_cofactor());
+            assert!(cleared.is_torsion_free());
+            assert!(!was_torsion_free);
+        }
+
+        let (cleared, was_torsion_free) = constants::EIGHT_TORSION[2].clear_and_check_torsion();
+        assert!(cleared.is_identity());
+        assert!(!was_torsion_free);
+    }
+
+    #[test]
+    fn conditional_add() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let P = B * Scalar::from(5u64);
+
+        assert_eq!(P.conditional_add(&B, Choice::from(1)), P + B);
+        assert_eq!(P.conditional_add(&B, Choice::from(0)), P);
+
+        let mut acc = EdwardsPoint::identity();
+        for bit in [1u8, 0, 1, 1, 0].iter() {
+            acc = acc.conditional_add(&B, Choice::from(*bit));
+        }
+        assert_eq!(acc, B * Scalar::from(3u64));
+    }
+
+    #[test]
+    fn verification_key_checks_schnorr_equation() {
+        let mut rng = rand::thread_rng();
+        let B = &constants::ED25519_BASEPOINT_TABLE;
+
+        let a = Scalar::random(&mut rng);
+        let A = B * &a;
+        let key = VerificationKey::new(A.compress()).unwrap();
+        assert_eq!(*key.point(), A);
+        assert_eq!(*key.compressed(), A.compress());
+
+        for _ in 0..4 {
+            let r = Scalar::random(&mut rng);
+            let k = Scalar::random(&mut rng);
+            let s = r + k * a;
+            let R = (B * &r).compress();
+
+            assert_eq!(
+                key.vartime_double_scalar_mul_basepoint(&k, &s),
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &-A, &s)
+            );
+            assert!(key.vartime_verify(&R, &k, &s));
+            assert!(!key.vartime_verify(&R, &k, &(s + Scalar::one())));
+            assert!(!key.vartime_verify(&R, &(k + Scalar::one()), &s));
+        }
+
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(VerificationKey::new(CompressedEdwardsY(bytes)).is_none());
+    }
+
+    #[test]
+    fn canonical_encodings_roundtrip() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            let P = EdwardsPoint::random(&mut rng);
+            assert!(P.is_canonical_roundtrip());
+            assert!(P.compress().is_canonical_decodable());
+        }
+        for T in constants::EIGHT_TORSION.iter() {
+            assert!(T.is_canonical_roundtrip());
+            assert!(T.compress().is_canonical_decodable());
+        }
+
+        // y = 2 does not decompress
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(!CompressedEdwardsY(bytes).is_canonical_decodable());
+
+        // The identity with the sign bit set, and with y = p + 1
+        let mut minus_zero = CompressedEdwardsY::identity().to_bytes();
+        minus_zero[31] |= 0x80;
+        assert!(!CompressedEdwardsY(minus_zero).is_canonical_decodable());
+        let mut unreduced = [0xffu8; 32];
+        unreduced[0] = 0xee;
+        unreduced[31] = 0x7f;
+        assert!(CompressedEdwardsY(unreduced).decompress().is_some());
+        assert!(!CompressedEdwardsY(unreduced).is_canonical_decodable());
+    }
+
     /// Test that computing 1*basepoint gives the correct basepoint.
     #[test]
     fn basepoint_mult_one_vs_basepoint() {
@@ -1431,6 +3700,35 @@ mod test {
         assert_eq!(aP128, aP256);
     }
 
+    #[test]
+    fn basepoint_table_multiples() {
+        let table = &constants::ED25519_BASEPOINT_TABLE;
+
+        let multiples = table.multiples(20).collect::<Vec<_>>();
+        assert_eq!(multiples.len(), 20);
+        for (i, P) in multiples.iter().enumerate() {
+            assert_eq!(*P, table * &Scalar::from(i as u64 + 1));
+        }
+
+        assert_eq!(table.multiples(0).count(), 0);
+    }
+
+    #[test]
+    fn basepoint_table_verify() {
+        assert!(constants::ED25519_BASEPOINT_TABLE.verify());
+
+        let mut table = constants::ED25519_BASEPOINT_TABLE.clone();
+        table.0[17].0[3] = table.0[17].0[4];
+        assert!(!table.verify());
+
+        let P = &constants::ED25519_BASEPOINT_POINT;
+        let mut table_radix64 = EdwardsBasepointTableRadix64::create(&P);
+        assert!(table_radix64.verify());
+        table_radix64.0[0].0[0] = table_radix64.0[0].0[1];
+        assert!(!table_radix64.verify());
+        assert!(EdwardsBasepointTableRadix256::create(&P).verify());
+    }
+
     // Check a unreduced scalar multiplication by the basepoint tables.
     #[test]
     fn basepoint_tables_unreduced_scalar() {
@@ -1522,6 +3820,171 @@ mod test {
         assert_eq!(p1, bp);
     }
 
+    #[test]
+    fn conditional_negate() {
+        let bp = constants::ED25519_BASEPOINT_POINT;
+
+        let mut P = bp;
+        P.conditional_negate(Choice::from(0));
+        assert_eq!(P, bp);
+        P.conditional_negate(Choice::from(1));
+        assert_eq!(P, -bp);
+        P.conditional_negate(Choice::from(1));
+        assert_eq!(P, bp);
+    }
+
+    #[test]
+    fn mul_constant_time_and_vartime_mul() {
+        let mut rng = rand::thread_rng();
+        let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+        let scalars = [
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            Scalar::random(&mut rng),
+            // Unreduced, as used by X25519
+            Scalar::from_bits([0xff; 32]),
+        ];
+
+        for s in scalars.iter() {
+            let expected = &P * s;
+            assert_eq!(P.mul_constant_time(s), expected);
+            assert_eq!(P.vartime_mul(s), expected);
+        }
+        let T = constants::EIGHT_TORSION[3];
+        assert_eq!(T.mul_constant_time(&Scalar::from(5u64)), constants::EIGHT_TORSION[7]);
+        assert_eq!(T.vartime_mul(&Scalar::from(5u64)), constants::EIGHT_TORSION[7]);
+    }
+
+    #[test]
+    fn random_is_torsion_free_and_not_identity() {
+        let mut rng = rand::thread_rng();
+        let P = EdwardsPoint::random(&mut rng);
+        let Q = EdwardsPoint::random(&mut rng);
+
+        assert!(P.is_torsion_free());
+        assert!(!P.is_identity());
+        assert_eq!(P.order(), PointOrder::Prime);
+        assert_ne!(P, Q);
+    }
+
+    #[test]
+    fn mul_by_inverse() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+        let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+
+        assert_eq!((&P * &s).mul_by_inverse(&s).unwrap(), P);
+        assert_eq!(P.mul_by_inverse(&s).unwrap(), &P * &s.invert());
+        assert!(bool::from(P.mul_by_inverse(&Scalar::zero()).is_none()));
+    }
+
+    #[test]
+    fn sub_scalar_mul() {
+        let mut rng = rand::thread_rng();
+        let c = Scalar::random(&mut rng);
+        let A = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+        let B = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+
+        assert_eq!(A.sub_scalar_mul(&c, &B), A - c * B);
+        assert_eq!(A.sub_scalar_mul(&Scalar::zero(), &B), A);
+    }
+
+    #[test]
+    fn batch_mul_matches_independent_products() {
+        let mut rng = rand::thread_rng();
+
+        let pairs = (0..8)
+            .map(|_| {
+                let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+                (Scalar::random(&mut rng), P)
+            })
+            .collect::<Vec<_>>();
+
+        let results = EdwardsPoint::batch_mul(&pairs);
+        assert_eq!(results.len(), pairs.len());
+        for ((a, P), aP) in pairs.iter().zip(results.iter()) {
+            assert_eq!(*aP, a * P);
+        }
+
+        assert!(EdwardsPoint::batch_mul(&[]).is_empty());
+    }
+
+    #[test]
+    fn vartime_grouped_multiscalar_matches_per_group() {
+        let mut rng = rand::thread_rng();
+
+        // Include an empty group, and one large enough for Pippenger
+        let pairs = (0..256)
+            .map(|_| (Scalar::random(&mut rng), EdwardsPoint::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let groups: Vec<&[(Scalar, EdwardsPoint)]> =
+            vec![&pairs[..1], &pairs[1..6], &[], &pairs[6..200], &pairs[200..256]];
+
+        let results = EdwardsPoint::vartime_grouped_multiscalar(&groups);
+        assert_eq!(results.len(), groups.len());
+        for (group, sum) in groups.iter().zip(results.iter()) {
+            let expected = EdwardsPoint::vartime_multiscalar_mul(
+                group.iter().map(|(a, _)| a),
+                group.iter().map(|(_, P)| P),
+            );
+            assert_eq!(*sum, expected);
+        }
+        assert!(results[2].is_identity());
+
+        assert!(EdwardsPoint::vartime_grouped_multiscalar(&[]).is_empty());
+    }
+
+    #[test]
+    fn ct_select_by_index() {
+        let points = constants::EIGHT_TORSION;
+
+        for (i, P) in points.iter().enumerate() {
+            assert_eq!(EdwardsPoint::ct_select(&points, i as u64), *P);
+        }
+        assert_eq!(EdwardsPoint::ct_select(&points, 8), EdwardsPoint::identity());
+        assert_eq!(EdwardsPoint::ct_select(&points, u64::max_value()), EdwardsPoint::identity());
+        assert_eq!(EdwardsPoint::ct_select(&[], 0), EdwardsPoint::identity());
+    }
+
+    #[test]
+    fn add_sub_matches_separate_operations() {
+        let mut rng = rand::thread_rng();
+        let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+        let Q = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+
+        assert_eq!(P.add_sub(&Q), (P + Q, P - Q));
+        assert_eq!(P.add_sub(&P), (P + P, EdwardsPoint::identity()));
+        assert_eq!(P.add_sub(&EdwardsPoint::identity()), (P, P));
+
+        let T = constants::EIGHT_TORSION[1];
+        assert_eq!(P.add_sub(&T), (P + T, P - T));
+    }
+
+    #[test]
+    fn cached_niels_point_arithmetic() {
+        let mut rng = rand::thread_rng();
+        let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+        let Q = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng);
+        let Q_niels = EdwardsNielsPoint::from(&Q);
+        let Q_affine_niels = EdwardsAffineNielsPoint::from(&Q);
+
+        assert_eq!(P + Q_niels, P + Q);
+        assert_eq!(P - Q_niels, P - Q);
+        assert_eq!(P + -&Q_niels, P - Q);
+        assert_eq!(P + Q_affine_niels, P + Q);
+        assert_eq!(P - Q_affine_niels, P - Q);
+        assert_eq!(P + -&Q_affine_niels, P - Q);
+
+        let mut acc = P;
+        for _ in 0..4 {
+            acc += Q_niels;
+            acc += &Q_affine_niels;
+        }
+        acc -= &Q_niels;
+        assert_eq!(acc, P + Q * Scalar::from(7u64));
+    }
+
     #[test]
     fn is_small_order() {
         // The basepoint has large prime order
@@ -1532,6 +3995,25 @@ mod test {
         }
     }
 
+    #[test]
+    fn point_order() {
+        let T = constants::EIGHT_TORSION;
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        assert_eq!(EdwardsPoint::identity().order(), PointOrder::One);
+        assert_eq!(T[4].order(), PointOrder::Two);
+        assert_eq!(T[2].order(), PointOrder::Four);
+        assert_eq!(T[6].order(), PointOrder::Four);
+        assert_eq!(T[1].order(), PointOrder::Eight);
+        assert_eq!(T[3].order(), PointOrder::Eight);
+
+        assert_eq!(B.order(), PointOrder::Prime);
+        assert_eq!(B.mul_by_cofactor().order(), PointOrder::Prime);
+        for torsion_point in &T[1..] {
+            assert_eq!((B + torsion_point).order(), PointOrder::Composite);
+        }
+    }
+
     #[test]
     fn compressed_identity() {
         assert_eq!(EdwardsPoint::identity().compress(),
@@ -1685,6 +4167,451 @@ mod test {
         assert_eq!(Q.compress(), R.compress());
     }
 
+    #[test]
+    fn vartime_precomputed_points_roundtrip() {
+        let mut rng = rand::thread_rng();
+
+        let B = &::constants::ED25519_BASEPOINT_TABLE;
+        let static_points = (0..8)
+            .map(|_| B * &Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let precomputation = VartimeEdwardsPrecomputation::new(static_points.iter());
+        assert_eq!(precomputation.len(), 8);
+        assert!(!precomputation.is_empty());
+        assert_eq!(precomputation.points().collect::<Vec<_>>(), static_points);
+
+        let empty = VartimeEdwardsPrecomputation::new(Vec::<EdwardsPoint>::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.points().count(), 0);
+    }
+
+    #[test]
+    fn vartime_precomputed_short_and_zero_scalars() {
+        let mut rng = rand::thread_rng();
+
+        let B = &::constants::ED25519_BASEPOINT_TABLE;
+        let static_points = (0..4)
+            .map(|_| B * &Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        let precomputation = VartimeEdwardsPrecomputation::new(static_points.iter());
+
+        let short_scalars = [Scalar::from(3u64), Scalar::from(u64::max_value()), Scalar::zero(), Scalar::one()];
+        let expected: EdwardsPoint = short_scalars
+            .iter()
+            .zip(static_points.iter())
+            .map(|(s, P)| s * P)
+            .sum();
+        assert_eq!(precomputation.vartime_multiscalar_mul(&short_scalars), expected);
+
+        let zero_scalars = [Scalar::zero(); 4];
+        assert_eq!(precomputation.vartime_multiscalar_mul(&zero_scalars), EdwardsPoint::identity());
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul(&zero_scalars, &static_points),
+            EdwardsPoint::identity()
+        );
+    }
+
+    #[test]
+    fn vartime_precomputed_append_and_concat() {
+        let mut rng = rand::thread_rng();
+
+        let B = &::constants::ED25519_BASEPOINT_TABLE;
+        let static_points = (0..12)
+            .map(|_| B * &Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        let static_scalars = (0..12)
+            .map(|_| Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let expected = VartimeEdwardsPrecomputation::new(static_points.iter())
+            .vartime_multiscalar_mul(&static_scalars);
+
+        let mut appended = VartimeEdwardsPrecomputation::new(&static_points[..5]);
+        appended.append(VartimeEdwardsPrecomputation::new(&static_points[5..]));
+        assert_eq!(appended.points().collect::<Vec<_>>(), static_points);
+        assert_eq!(appended.vartime_multiscalar_mul(&static_scalars), expected);
+
+        let concatenated = VartimeEdwardsPrecomputation::concat(vec![
+            VartimeEdwardsPrecomputation::new(&static_points[..3]),
+            VartimeEdwardsPrecomputation::new(Vec::<EdwardsPoint>::new()),
+            VartimeEdwardsPrecomputation::new(&static_points[3..]),
+        ]);
+        assert_eq!(concatenated.len(), 12);
+        assert_eq!(concatenated.vartime_multiscalar_mul(&static_scalars), expected);
+    }
+
+    #[test]
+    fn vartime_precomputed_footprint() {
+        let B = &::constants::ED25519_BASEPOINT_TABLE;
+        let static_points = (1..=6u64)
+            .map(|i| B * &Scalar::from(i))
+            .collect::<Vec<_>>();
+
+        let precomputation = VartimeEdwardsPrecomputation::new(static_points.iter());
+        assert!(precomputation.footprint() >= VartimeEdwardsPrecomputation::footprint_bytes(6));
+        assert_eq!(VartimeEdwardsPrecomputation::footprint_bytes(0), 0);
+        assert_eq!(
+            VartimeEdwardsPrecomputation::footprint_bytes(100),
+            100 * VartimeEdwardsPrecomputation::footprint_bytes(1)
+        );
+        #[cfg(not(all(
+            feature = "simd_backend",
+            any(target_feature = "avx2", target_feature = "avx512ifma")
+        )))]
+        assert_eq!(VartimeEdwardsPrecomputation::footprint_bytes(1), 7680);
+    }
+
+    #[test]
+    fn all_distinct() {
+        assert!(EdwardsPoint::all_distinct(&[]));
+        assert!(EdwardsPoint::all_distinct(&constants::EIGHT_TORSION));
+
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let generators = constants::ED25519_BASEPOINT_TABLE.multiples(16).collect::<Vec<_>>();
+        assert!(EdwardsPoint::all_distinct(&generators));
+
+        // A collision, in a different projective representation
+        let mut colliding = generators.clone();
+        colliding.push(B.double() - B);
+        assert!(!EdwardsPoint::all_distinct(&colliding));
+    }
+
+    #[test]
+    fn negate_batch() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<EdwardsPoint> = (0..5).map(|_| EdwardsPoint::random(&mut rng)).collect();
+
+        let mut negated = points.clone();
+        EdwardsPoint::negate_batch(&mut negated);
+        for (P, minus_P) in points.iter().zip(negated.iter()) {
+            assert_eq!(*minus_P, -P);
+            assert!(minus_P.is_valid());
+        }
+
+        EdwardsPoint::negate_batch(&mut []);
+    }
+
+    #[test]
+    fn compressed_eq_ignore_sign() {
+        let mut rng = rand::thread_rng();
+        let P = EdwardsPoint::random(&mut rng);
+        let Q = EdwardsPoint::random(&mut rng);
+
+        assert!(P.compress().eq_ignore_sign(&P.compress()));
+        assert!(P.compress().eq_ignore_sign(&(-P).compress()));
+        assert_ne!(P.compress(), (-P).compress());
+        assert!(!P.compress().eq_ignore_sign(&Q.compress()));
+        assert!(!P.compress().eq_ignore_sign(&(-Q).compress()));
+    }
+
+    #[test]
+    fn to_montgomery_affine() {
+        // The RFC 7748 basepoint (9, v)
+        let (u, v) = constants::ED25519_BASEPOINT_POINT.to_montgomery_affine().unwrap();
+        assert_eq!(u, constants::X25519_BASEPOINT.to_bytes());
+        assert_eq!(
+            hex::encode(v),
+            "d9d3ce7ea2c5e929b2617c6d7e4d3d924cd148772cdd1ee0b486a0b8a119ae20"
+        );
+
+        assert_eq!(EdwardsPoint::identity().to_montgomery_affine(), None);
+        assert_eq!(constants::EIGHT_TORSION[4].to_montgomery_affine(), Some(([0u8; 32], [0u8; 32])));
+
+        let mut rng = rand::thread_rng();
+        let points = (0..8).map(|_| EdwardsPoint::random(&mut rng))
+            .chain(constants::EIGHT_TORSION[1..].iter().cloned());
+        for P in points {
+            let (u, v) = P.to_montgomery_affine().unwrap();
+            assert_eq!(u, P.to_montgomery().to_bytes());
+
+            // (u, v) is on the curve v^2 = u^3 + A u^2 + u
+            let u_fe = FieldElement::from_bytes(&u);
+            let v_fe = FieldElement::from_bytes(&v);
+            let rhs = &(&(&u_fe.square() + &(&constants::MONTGOMERY_A * &u_fe)) * &u_fe) + &u_fe;
+            assert_eq!(v_fe.square(), rhs);
+
+            // and maps back to P, given the sign of x
+            let sign = P.compress().as_bytes()[31] >> 7;
+            assert_eq!(MontgomeryPoint(u).to_edwards(sign), Some(P));
+        }
+    }
+
+    #[test]
+    fn compress_ct_into_matches_compress() {
+        let mut rng = rand::thread_rng();
+        let points = [
+            EdwardsPoint::identity(),
+            constants::ED25519_BASEPOINT_POINT,
+            -constants::ED25519_BASEPOINT_POINT,
+            constants::EIGHT_TORSION[3],
+            EdwardsPoint::random(&mut rng),
+        ];
+
+        for P in points.iter() {
+            // Start from a dirty buffer, which must be fully overwritten
+            let mut out = [0xffu8; 32];
+            P.compress_ct_into(&mut out);
+            assert_eq!(out, P.compress().to_bytes());
+        }
+    }
+
+    #[test]
+    fn compress_with_flags() {
+        let (compressed, is_identity) = EdwardsPoint::identity().compress_with_flags();
+        assert_eq!(compressed, CompressedEdwardsY::identity());
+        assert!(bool::from(is_identity));
+
+        // Nonzero torsion points, and the basepoint
+        for P in constants::EIGHT_TORSION.iter().skip(1).chain(Some(&constants::ED25519_BASEPOINT_POINT)) {
+            let (compressed, is_identity) = P.compress_with_flags();
+            assert_eq!(compressed, P.compress());
+            assert!(!bool::from(is_identity));
+        }
+    }
+
+    #[test]
+    fn comb_table_matches_basepoint_table() {
+        let mut rng = rand::thread_rng();
+        let B = EdwardsPoint::random(&mut rng);
+        let table = EdwardsBasepointTableRadix16::create(&B);
+
+        let mut scalars: Vec<Scalar> = (0..4).map(|_| Scalar::random(&mut rng)).collect();
+        scalars.push(Scalar::zero());
+        scalars.push(Scalar::from_bits([0xff; 32]));
+
+        for w in 1..=8 {
+            let comb = EdwardsCombTable::create(&B, w);
+            assert_eq!(comb.basepoint(), B);
+            for a in scalars.iter() {
+                assert_eq!(&comb * a, &table * a);
+                assert_eq!(a * &comb, &table * a);
+            }
+        }
+    }
+
+    #[test]
+    fn multiscalar_difference() {
+        let mut rng = rand::thread_rng();
+        let a: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut rng)).collect();
+        let P: Vec<EdwardsPoint> = (0..3).map(|_| EdwardsPoint::random(&mut rng)).collect();
+        let b: Vec<Scalar> = (0..2).map(|_| Scalar::random(&mut rng)).collect();
+        let Q: Vec<EdwardsPoint> = (0..2).map(|_| EdwardsPoint::random(&mut rng)).collect();
+
+        let expected = EdwardsPoint::multiscalar_mul(&a, &P) - EdwardsPoint::multiscalar_mul(&b, &Q);
+        assert_eq!(EdwardsPoint::multiscalar_difference(&a, &P, &b, &Q), expected);
+        assert_eq!(EdwardsPoint::vartime_multiscalar_difference(&a, &P, &b, &Q), expected);
+
+        // Two different expressions of the same point
+        let two = Scalar::from(2u64);
+        let diff = EdwardsPoint::vartime_multiscalar_difference(&[a[0], a[0]], &P[..2], &[a[0] * two], &[P[1]]);
+        assert_eq!(diff, a[0] * (P[0] - P[1]));
+        let same = EdwardsPoint::vartime_multiscalar_difference(&[a[0], a[0]], &[P[0], P[0]], &[a[0] * two], &P[..1]);
+        assert!(same.is_identity());
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn multiscalar_mul_with_stats() {
+        let mut rng = rand::thread_rng();
+
+        // Straus: the NAFs of 1 and 3 are single digits in the lowest row.
+        let P = [EdwardsPoint::random(&mut rng), EdwardsPoint::random(&mut rng)];
+        let s = [Scalar::one(), Scalar::from(3u64)];
+        let (result, stats) = EdwardsPoint::vartime_multiscalar_mul_with_stats(&s, &P);
+        assert_eq!(result, P[0] + P[1] * s[1]);
+        let expected = MultiscalarStats { doublings: 2 + 1, additions: 2 * 7 + 2, window: 5 };
+        assert_eq!(stats, expected);
+
+        // Pippenger, with a window of 6 bits for 200 points
+        let s: Vec<Scalar> = (0..200).map(|_| Scalar::random(&mut rng)).collect();
+        let P: Vec<EdwardsPoint> = (0..200).map(|_| EdwardsPoint::random(&mut rng)).collect();
+        let (result, stats) = EdwardsPoint::vartime_multiscalar_mul_with_stats(&s, &P);
+        assert_eq!(result, EdwardsPoint::vartime_multiscalar_mul(&s, &P));
+
+        let digits_count = Scalar::to_radix_2w_size_hint(6);
+        let nonzero_digits: usize = s
+            .iter()
+            .map(|s| s.to_radix_2w(6).iter().filter(|&&d| d != 0).count())
+            .sum();
+        let expected = MultiscalarStats {
+            doublings: 6 * (digits_count - 1),
+            additions: nonzero_digits + digits_count * 2 * 31 + (digits_count - 1),
+            window: 6,
+        };
+        assert_eq!(stats, expected);
+    }
+
+    #[test]
+    fn vartime_multiscalar_mul_2_and_3() {
+        let mut rng = rand::thread_rng();
+        let s: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut rng)).collect();
+        let P: Vec<EdwardsPoint> = (0..3).map(|_| EdwardsPoint::random(&mut rng)).collect();
+
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul_2(&s[0], &P[0], &s[1], &P[1]),
+            EdwardsPoint::vartime_multiscalar_mul(&s[..2], &P[..2])
+        );
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul_3(&s[0], &P[0], &s[1], &P[1], &s[2], &P[2]),
+            EdwardsPoint::vartime_multiscalar_mul(&s, &P)
+        );
+
+        let zero = Scalar::zero();
+        assert!(EdwardsPoint::vartime_multiscalar_mul_2(&zero, &P[0], &zero, &P[1]).is_identity());
+        let unreduced = Scalar::from_bits([0xff; 32]);
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul_2(&unreduced, &P[0], &s[1], &P[1]),
+            EdwardsPoint::vartime_multiscalar_mul(&[unreduced, s[1]], &P[..2])
+        );
+    }
+
+    #[test]
+    fn multiscalar_mul_partial() {
+        let mut rng = rand::thread_rng();
+        let s: Vec<Scalar> = (0..17).map(|_| Scalar::random(&mut rng)).collect();
+        let P: Vec<EdwardsPoint> = (0..17).map(|_| EdwardsPoint::random(&mut rng)).collect();
+        let full = EdwardsPoint::multiscalar_mul(&s, &P);
+
+        for &(a, b) in [(0, 17), (1, 16), (5, 5), (8, 9)].iter() {
+            let parts = EdwardsPoint::multiscalar_mul_partial(&s, &P, 0..a)
+                + EdwardsPoint::multiscalar_mul_partial(&s, &P, a..b)
+                + EdwardsPoint::multiscalar_mul_partial(&s, &P, b..17);
+            assert_eq!(parts, full);
+        }
+        assert!(EdwardsPoint::multiscalar_mul_partial(&s, &P, 3..3).is_identity());
+    }
+
+    #[test]
+    fn multiscalar_mul_eq() {
+        let mut rng = rand::thread_rng();
+        let scalars: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<EdwardsPoint> = (0..5)
+            .map(|_| &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut rng))
+            .collect();
+        let sum = EdwardsPoint::multiscalar_mul(&scalars, &points);
+        let not_sum = sum + constants::ED25519_BASEPOINT_POINT;
+        // Same point with a different Z coordinate
+        let sum_rescaled = EdwardsPoint {
+            X: &sum.X + &sum.X,
+            Y: &sum.Y + &sum.Y,
+            Z: &sum.Z + &sum.Z,
+            T: &sum.T + &sum.T,
+        };
+        assert!(sum_rescaled.is_valid());
+
+        assert!(bool::from(EdwardsPoint::multiscalar_mul_eq(&scalars, &points, &sum)));
+        assert!(bool::from(EdwardsPoint::multiscalar_mul_eq(&scalars, &points, &sum_rescaled)));
+        assert!(!bool::from(EdwardsPoint::multiscalar_mul_eq(&scalars, &points, &not_sum)));
+
+        assert!(EdwardsPoint::vartime_multiscalar_mul_eq(&scalars, &points, &sum));
+        assert!(EdwardsPoint::vartime_multiscalar_mul_eq(&scalars, &points, &sum_rescaled));
+        assert!(!EdwardsPoint::vartime_multiscalar_mul_eq(&scalars, &points, &not_sum));
+    }
+
+    #[test]
+    fn multiscalar_mul_with_window() {
+        let mut rng = rand::thread_rng();
+
+        let B = &::constants::ED25519_BASEPOINT_TABLE;
+        let scalars = (0..16)
+            .map(|_| Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        let points = (0..16)
+            .map(|_| B * &Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let expected = EdwardsPoint::vartime_multiscalar_mul(&scalars, &points);
+        for w in 4..=8 {
+            let P = EdwardsPoint::optional_multiscalar_mul_with_window(
+                &scalars,
+                points.iter().map(|P| Some(*P)),
+                w,
+            );
+            assert_eq!(P, Some(expected));
+        }
+
+        let with_none = EdwardsPoint::optional_multiscalar_mul_with_window(
+            &scalars,
+            points.iter().map(|_| None),
+            6,
+        );
+        assert!(with_none.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiscalar_mul_with_window_rejects_large_window() {
+        EdwardsPoint::optional_multiscalar_mul_with_window(
+            &[Scalar::one()],
+            vec![Some(constants::ED25519_BASEPOINT_POINT)],
+            9,
+        );
+    }
+
+    #[test]
+    fn vartime_multiscalar_mul_small_vs_full_scalars() {
+        let mut rng = rand::thread_rng();
+
+        let B = &::constants::ED25519_BASEPOINT_TABLE;
+        let coeffs: [i64; 6] = [-3, 7, 0, 1 << 40, -(1 << 62), i64::min_value()];
+        let points = (0..coeffs.len())
+            .map(|_| B * &Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let scalars = coeffs
+            .iter()
+            .map(|c| {
+                let s = Scalar::from(c.wrapping_abs() as u64);
+                if *c < 0 { -s } else { s }
+            })
+            .collect::<Vec<_>>();
+
+        use traits::VartimeMultiscalarMul;
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul_small(&coeffs, &points),
+            EdwardsPoint::vartime_multiscalar_mul(&scalars, &points),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "const_generics")]
+    fn precomputed_straus_array_vs_nonprecomputed_multiscalar() {
+        let mut rng = rand::thread_rng();
+
+        let B = &::constants::ED25519_BASEPOINT_TABLE;
+
+        let mut static_scalars = [Scalar::zero(); 8];
+        let mut dynamic_scalars = [Scalar::zero(); 4];
+        for s in static_scalars.iter_mut().chain(dynamic_scalars.iter_mut()) {
+            *s = Scalar::random(&mut rng);
+        }
+
+        let mut static_points = [EdwardsPoint::identity(); 8];
+        for (P, s) in static_points.iter_mut().zip(static_scalars.iter()) {
+            *P = s * B;
+        }
+        let mut dynamic_points = [EdwardsPoint::identity(); 4];
+        for (P, s) in dynamic_points.iter_mut().zip(dynamic_scalars.iter()) {
+            *P = s * B;
+        }
+
+        let static_check: Scalar = static_scalars.iter().map(|s| s * s).sum();
+        let dynamic_check: Scalar = dynamic_scalars.iter().map(|s| s * s).sum();
+
+        let precomputation = PrecomputedStrausArray::new(&static_points);
+
+        let P = precomputation.vartime_multiscalar_mul(&static_scalars);
+        assert_eq!(P.compress(), (&static_check * B).compress());
+
+        let Q = precomputation.vartime_mixed_multiscalar_mul(
+            &static_scalars,
+            &dynamic_scalars,
+            &dynamic_points,
+        );
+        assert_eq!(Q.compress(), (&(static_check + dynamic_check) * B).compress());
+    }
+
     mod vartime {
         use super::super::*;
         use super::{A_SCALAR, B_SCALAR, A_TIMES_BASEPOINT, DOUBLE_SCALAR_MULT_RESULT};
@@ -1723,6 +4650,37 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn compressed_read_from_write_to() {
+        use std::io::ErrorKind;
+
+        let mut buf = Vec::new();
+        constants::ED25519_BASEPOINT_COMPRESSED.write_to(&mut buf).unwrap();
+        assert_eq!(&buf[..], constants::ED25519_BASEPOINT_COMPRESSED.as_bytes());
+
+        let mut reader = &buf[..];
+        assert_eq!(
+            CompressedEdwardsY::read_from(&mut reader, true).unwrap(),
+            constants::ED25519_BASEPOINT_COMPRESSED
+        );
+        let err = CompressedEdwardsY::read_from(&mut reader, true).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        // y = 2 is not the y-coordinate of a curve point
+        let mut invalid = [0u8; 32];
+        invalid[0] = 2;
+        let err = CompressedEdwardsY::read_from(&mut &invalid[..], false).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // The identity with the sign bit set decompresses, but is not canonical
+        let mut non_canonical = CompressedEdwardsY::identity().to_bytes();
+        non_canonical[31] |= 0x80;
+        assert!(CompressedEdwardsY::read_from(&mut &non_canonical[..], false).is_ok());
+        let err = CompressedEdwardsY::read_from(&mut &non_canonical[..], true).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde_bincode_basepoint_roundtrip() {
@@ -1807,4 +4765,21 @@ mod test {
             assert_eq!(point.compress().to_bytes(), output[..]);
         }
     }
+
+    #[test]
+    fn from_hash_matches_hash_from_bytes() {
+        for vector in test_vectors().iter() {
+            let input = hex::decode(vector[0]).unwrap();
+
+            // Stream the input in two pieces
+            let mut hash = sha2::Sha512::new();
+            hash.update(&input[..input.len() / 2]);
+            hash.update(&input[input.len() / 2..]);
+
+            assert_eq!(
+                EdwardsPoint::from_hash(hash),
+                EdwardsPoint::hash_from_bytes::<sha2::Sha512>(&input)
+            );
+        }
+    }
 }
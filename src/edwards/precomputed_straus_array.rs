@@ -0,0 +1,67 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Precomputed multiscalar multiplication with a fixed number of static
+//! points.  This lives in its own module, enabled by the
+//! `const_generics` feature, since const generics need a newer compiler
+//! than the rest of the crate.
+
+#![allow(non_snake_case)]
+
+use edwards::EdwardsPoint;
+use scalar::Scalar;
+
+use super::scalar_mul;
+
+/// Precomputation for variable-time multiscalar multiplication with
+/// exactly `N` static `EdwardsPoint`s.
+///
+/// Unlike `VartimeEdwardsPrecomputation`, the lookup tables are stored
+/// inline in an array, so this type is available without `alloc`.
+/// Each static point takes about 7.5KB of table space, so large
+/// instances should probably be boxed.
+// This wraps the inner implementation in a facade type so that we can
+// decouple stability of the inner type from the stability of the
+// outer type.
+pub struct PrecomputedStrausArray<const N: usize>(
+    scalar_mul::precomputed_straus_array::VartimePrecomputedStrausArray<N>,
+);
+
+impl<const N: usize> PrecomputedStrausArray<N> {
+    /// Given the static points \\( B\_i \\), perform precomputation
+    /// and return the precomputation data.
+    pub fn new(static_points: &[EdwardsPoint; N]) -> Self {
+        Self(scalar_mul::precomputed_straus_array::VartimePrecomputedStrausArray::new(static_points))
+    }
+
+    /// Given public scalars \\( b\_i \\), compute
+    /// $$
+    /// Q = b\_1 B\_1 + \cdots + b\_N B\_N,
+    /// $$
+    /// where the \\(B\_i\\) are the points that were supplied to `new`.
+    ///
+    /// This function is **not** constant time.
+    pub fn vartime_multiscalar_mul(&self, static_scalars: &[Scalar; N]) -> EdwardsPoint {
+        self.0.vartime_mixed_multiscalar_mul(static_scalars, &[], &[])
+    }
+
+    /// Given public scalars \\( a\_j \\), public points \\( A\_j \\), and
+    /// public scalars \\( b\_i \\), compute
+    /// $$
+    /// Q = a\_1 A\_1 + \cdots + a\_M A\_M + b\_1 B\_1 + \cdots + b\_N B\_N,
+    /// $$
+    /// where the \\(B\_i\\) are the points that were supplied to `new`.
+    ///
+    /// This function is **not** constant time.
+    pub fn vartime_mixed_multiscalar_mul<const M: usize>(
+        &self,
+        static_scalars: &[Scalar; N],
+        dynamic_scalars: &[Scalar; M],
+        dynamic_points: &[EdwardsPoint; M],
+    ) -> EdwardsPoint {
+        self.0
+            .vartime_mixed_multiscalar_mul(static_scalars, dynamic_scalars, dynamic_points)
+    }
+}
@@ -0,0 +1,258 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Hex and base64 text encodings of points and scalars.
+//!
+//! These are provided as inherent methods, such as
+//! `CompressedEdwardsY::from_hex` and `Scalar::to_base64`, when the
+//! `encoding` feature is enabled.  This module only holds the error
+//! type they return.
+//!
+//! Hex output is lowercase, and hex input may use either case.  Base64
+//! uses the standard alphabet with padding (RFC 4648, section 4), and
+//! decoding is strict: a 32-byte value must be encoded in exactly 44
+//! characters, and the unused bits of the last character must be zero.
+//!
+//! Since scalars are often secret, encoding and decoding avoid branches
+//! and table lookups on the data; only the length of the input and the
+//! final success or failure are revealed.
+
+use core::fmt;
+
+use prelude::*;
+
+/// An error in decoding a hex or base64 string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DecodingError {
+    /// The input does not have the length of an encoded 32-byte value.
+    InvalidLength,
+    /// The input contains a character outside of the encoding's
+    /// alphabet, or is otherwise not a canonical encoding.
+    InvalidEncoding,
+    /// The input decodes to bytes which are not the canonical encoding
+    /// of a `Scalar`.
+    NonCanonicalScalar,
+}
+
+impl fmt::Display for DecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodingError::InvalidLength => write!(f, "invalid encoded length"),
+            DecodingError::InvalidEncoding => write!(f, "invalid character or padding"),
+            DecodingError::NonCanonicalScalar => write!(f, "non-canonical scalar encoding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for DecodingError {}
+
+/// The length of the hex encoding of 32 bytes.
+const HEX_LEN: usize = 64;
+
+/// The length of the padded base64 encoding of 32 bytes.
+const BASE64_LEN: usize = 44;
+
+/// Encode `n < 16` as a lowercase hex digit.
+fn encode_nibble(n: u8) -> u8 {
+    let n = n as i16;
+    // Add '0', and the gap up to 'a' when n > 9.
+    (n + 0x30 + (((9 - n) >> 8) & 0x27)) as u8
+}
+
+/// Decode a hex digit, returning `-1` if `c` is not a hex digit.
+fn decode_nibble(c: u8) -> i16 {
+    let c = c as i16;
+    let mut ret: i16 = -1;
+    // Each term is `(value + 1)` if `c` is in the range, and 0 otherwise.
+    ret += (((0x2f - c) & (c - 0x3a)) >> 8) & (c - 47); // '0'..='9'
+    ret += (((0x40 - c) & (c - 0x47)) >> 8) & (c - 54); // 'A'..='F'
+    ret += (((0x60 - c) & (c - 0x67)) >> 8) & (c - 86); // 'a'..='f'
+    ret
+}
+
+/// Encode `n < 64` as a character of the standard base64 alphabet.
+fn encode_sextet(n: u8) -> u8 {
+    let n = n as i16;
+    let mut diff: i16 = 0x41; // 'A'
+    diff += ((25 - n) >> 8) & 6; // 'a' - 'A' - 26
+    diff -= ((51 - n) >> 8) & 75; // 'a' - '0' + 26 - 52
+    diff -= ((61 - n) >> 8) & 15; // '0' - '+' + 52 - 62
+    diff += ((62 - n) >> 8) & 3; // '/' - '+' - 1
+    (n + diff) as u8
+}
+
+/// Decode a character of the standard base64 alphabet, returning `-1`
+/// if `c` is not in the alphabet.
+fn decode_sextet(c: u8) -> i16 {
+    let c = c as i16;
+    let mut ret: i16 = -1;
+    // Each term is `(value + 1)` if `c` is in the range, and 0 otherwise.
+    ret += (((0x40 - c) & (c - 0x5b)) >> 8) & (c - 64); // 'A'..='Z'
+    ret += (((0x60 - c) & (c - 0x7b)) >> 8) & (c - 70); // 'a'..='z'
+    ret += (((0x2f - c) & (c - 0x3a)) >> 8) & (c + 5); // '0'..='9'
+    ret += (((0x2a - c) & (c - 0x2c)) >> 8) & 63; // '+'
+    ret += (((0x2e - c) & (c - 0x30)) >> 8) & 64; // '/'
+    ret
+}
+
+pub(crate) fn encode_hex(bytes: &[u8; 32]) -> String {
+    let mut out = Vec::with_capacity(HEX_LEN);
+    for b in bytes.iter() {
+        out.push(encode_nibble(b >> 4));
+        out.push(encode_nibble(b & 0xf));
+    }
+    String::from_utf8(out).expect("hex digits are ASCII")
+}
+
+pub(crate) fn decode_hex(s: &str) -> Result<[u8; 32], DecodingError> {
+    let s = s.as_bytes();
+    if s.len() != HEX_LEN {
+        return Err(DecodingError::InvalidLength);
+    }
+
+    let mut bytes = [0u8; 32];
+    let mut invalid: i16 = 0;
+    for (b, pair) in bytes.iter_mut().zip(s.chunks_exact(2)) {
+        let hi = decode_nibble(pair[0]);
+        let lo = decode_nibble(pair[1]);
+        invalid |= hi | lo;
+        *b = ((hi << 4) | lo) as u8;
+    }
+
+    if invalid < 0 {
+        return Err(DecodingError::InvalidEncoding);
+    }
+    Ok(bytes)
+}
+
+pub(crate) fn encode_base64(bytes: &[u8; 32]) -> String {
+    let mut out = Vec::with_capacity(BASE64_LEN);
+    let mut chunks = bytes.chunks_exact(3);
+    for chunk in chunks.by_ref() {
+        out.push(encode_sextet(chunk[0] >> 2));
+        out.push(encode_sextet(((chunk[0] & 0x03) << 4) | (chunk[1] >> 4)));
+        out.push(encode_sextet(((chunk[1] & 0x0f) << 2) | (chunk[2] >> 6)));
+        out.push(encode_sextet(chunk[2] & 0x3f));
+    }
+    // 32 = 3 * 10 + 2, so two bytes are left for the final, padded group.
+    let rest = chunks.remainder();
+    out.push(encode_sextet(rest[0] >> 2));
+    out.push(encode_sextet(((rest[0] & 0x03) << 4) | (rest[1] >> 4)));
+    out.push(encode_sextet((rest[1] & 0x0f) << 2));
+    out.push(b'=');
+    String::from_utf8(out).expect("base64 characters are ASCII")
+}
+
+pub(crate) fn decode_base64(s: &str) -> Result<[u8; 32], DecodingError> {
+    let s = s.as_bytes();
+    if s.len() != BASE64_LEN {
+        return Err(DecodingError::InvalidLength);
+    }
+
+    let mut bytes = [0u8; 32];
+    let mut invalid: i16 = 0;
+    for (out, group) in bytes.chunks_mut(3).zip(s.chunks_exact(4)) {
+        let c0 = decode_sextet(group[0]);
+        let c1 = decode_sextet(group[1]);
+        let c2 = decode_sextet(group[2]);
+        invalid |= c0 | c1 | c2;
+
+        out[0] = ((c0 << 2) | (c1 >> 4)) as u8;
+        out[1] = ((c1 << 4) | (c2 >> 2)) as u8;
+        if out.len() == 3 {
+            let c3 = decode_sextet(group[3]);
+            invalid |= c3;
+            out[2] = ((c2 << 6) | c3) as u8;
+        } else {
+            // The final group holds two bytes: it must end with padding,
+            // and the low two bits of its third character must be zero.
+            invalid |= -((group[3] != b'=') as i16);
+            invalid |= -(((c2 & 0x03) != 0) as i16);
+        }
+    }
+
+    if invalid < 0 {
+        return Err(DecodingError::InvalidEncoding);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use constants;
+    use edwards::CompressedEdwardsY;
+    use scalar::Scalar;
+
+    const BASE64_ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    #[test]
+    fn digit_encodings_match_alphabets() {
+        for n in 0..16u8 {
+            assert_eq!(encode_nibble(n), b"0123456789abcdef"[n as usize]);
+        }
+        for n in 0..64u8 {
+            assert_eq!(encode_sextet(n), BASE64_ALPHABET[n as usize]);
+        }
+        for c in 0..=255u8 {
+            let expected_nibble = (c as char).to_digit(16).map_or(-1, |d| d as i16);
+            assert_eq!(decode_nibble(c), expected_nibble);
+            let expected_sextet = BASE64_ALPHABET.iter().position(|&a| a == c).map_or(-1, |i| i as i16);
+            assert_eq!(decode_sextet(c), expected_sextet);
+        }
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn basepoint_encodings() {
+        let B = constants::ED25519_BASEPOINT_COMPRESSED;
+        let hex = "5866666666666666666666666666666666666666666666666666666666666666";
+        let base64 = "WGZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmY=";
+
+        assert_eq!(B.to_hex(), hex);
+        assert_eq!(B.to_base64(), base64);
+        assert_eq!(CompressedEdwardsY::from_hex(hex), Ok(B));
+        assert_eq!(CompressedEdwardsY::from_hex(&hex.to_uppercase()), Ok(B));
+        assert_eq!(CompressedEdwardsY::from_base64(base64), Ok(B));
+    }
+
+    #[test]
+    fn scalar_roundtrips() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(s.to_hex(), hex::encode(s.as_bytes()));
+            assert_eq!(Scalar::from_hex(&s.to_hex()), Ok(s));
+            assert_eq!(Scalar::from_base64(&s.to_base64()), Ok(s));
+        }
+    }
+
+    #[test]
+    fn malformed_inputs() {
+        let hex = constants::ED25519_BASEPOINT_COMPRESSED.to_hex();
+        let base64 = constants::ED25519_BASEPOINT_COMPRESSED.to_base64();
+
+        assert_eq!(CompressedEdwardsY::from_hex(&hex[2..]), Err(DecodingError::InvalidLength));
+        assert_eq!(CompressedEdwardsY::from_base64(&base64[..43]), Err(DecodingError::InvalidLength));
+
+        let bad_hex = format!("x{}", &hex[1..]);
+        assert_eq!(CompressedEdwardsY::from_hex(&bad_hex), Err(DecodingError::InvalidEncoding));
+        let bad_base64 = format!("{}-{}", &base64[..20], &base64[21..]);
+        assert_eq!(CompressedEdwardsY::from_base64(&bad_base64), Err(DecodingError::InvalidEncoding));
+
+        // Missing padding, and nonzero unused bits in the last character
+        let unpadded = format!("{}A", &base64[..43]);
+        assert_eq!(CompressedEdwardsY::from_base64(&unpadded), Err(DecodingError::InvalidEncoding));
+        let noncanonical = format!("{}Z=", &base64[..42]);
+        assert_eq!(CompressedEdwardsY::from_base64(&noncanonical), Err(DecodingError::InvalidEncoding));
+
+        // 2^256 - 1 is not reduced mod l
+        let all_ones = "ff".repeat(32);
+        assert_eq!(Scalar::from_hex(&all_ones), Err(DecodingError::NonCanonicalScalar));
+        assert!(CompressedEdwardsY::from_hex(&all_ones).is_ok());
+    }
+}
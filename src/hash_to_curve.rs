@@ -0,0 +1,142 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Building blocks for hashing to the curve, following [RFC 9380].
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use digest::generic_array::typenum::Unsigned;
+use digest::{BlockInput, Digest};
+
+use prelude::*;
+
+/// Expand `msg` into `len_in_bytes` uniformly random bytes, using the
+/// hash function `D` and the domain separation tag `dst`, according to
+/// `expand_message_xmd` from [section 5.3.1 of RFC 9380][xmd].
+///
+/// The message is hashed once, in the first block; the output is then
+/// derived from that hash in `ceil(len_in_bytes / b)` further hash
+/// invocations, where `b` is the output size of `D`.
+///
+/// A `dst` longer than 255 bytes is first hashed, as described in
+/// [section 5.3.3][oversize].
+///
+/// # Panics
+///
+/// If `len_in_bytes` is greater than 65535, or than 255 times the
+/// output size of `D`.
+///
+/// # Example
+///
+/// ```
+/// # extern crate curve25519_dalek;
+/// # extern crate sha2;
+/// use curve25519_dalek::hash_to_curve::expand_message_xmd;
+/// use sha2::Sha512;
+///
+/// # fn main() {
+/// let bytes = expand_message_xmd::<Sha512>(b"message", b"MY-APP-V1", 64);
+/// assert_eq!(bytes.len(), 64);
+/// # }
+/// ```
+///
+/// [xmd]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1
+/// [oversize]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.3
+pub fn expand_message_xmd<D>(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8>
+where
+    D: Digest + BlockInput,
+{
+    let b_in_bytes = D::output_size();
+    let r_in_bytes = D::BlockSize::to_usize();
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+    assert!(ell <= 255 && len_in_bytes <= 65535, "expand_message_xmd output is too long");
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = D::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = D::new()
+        .chain(vec![0u8; r_in_bytes])
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut b_i = D::new().chain(&b_0).chain([1u8]).chain(dst).chain(dst_len).finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let mut xored = b_0.clone();
+        for (x, y) in xored.iter_mut().zip(b_i.iter()) {
+            *x ^= y;
+        }
+        b_i = D::new().chain(&xored).chain([i as u8]).chain(dst).chain(dst_len).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Test vectors from appendices K.1 and K.3 of RFC 9380.
+
+    #[test]
+    fn expand_message_xmd_sha256() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 4] = [
+            (b"", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            (b"abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            (b"abcdef0123456789", 0x20, "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+
+        for (msg, len, expected) in vectors.iter() {
+            let output = expand_message_xmd::<sha2::Sha256>(msg, dst, *len);
+            assert_eq!(hex::encode(output), *expected);
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_sha512() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let output = expand_message_xmd::<sha2::Sha512>(b"", dst, 0x20);
+        assert_eq!(hex::encode(output), "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba");
+    }
+
+    #[test]
+    fn expand_message_xmd_oversize_dst() {
+        let long_dst = [b'X'; 300];
+        let hashed_dst = sha2::Sha512::new().chain(b"H2C-OVERSIZE-DST-").chain(&long_dst[..]).finalize();
+        assert_eq!(
+            expand_message_xmd::<sha2::Sha512>(b"abc", &long_dst, 48),
+            expand_message_xmd::<sha2::Sha512>(b"abc", &hashed_dst, 48),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn expand_message_xmd_too_long() {
+        expand_message_xmd::<sha2::Sha256>(b"", b"DST", 255 * 32 + 1);
+    }
+}
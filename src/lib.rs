@@ -124,6 +124,18 @@
 //! selecting an arithmetic backend using one of the `_backend` features.
 //! If no backend is selected, compilation will fail.
 //!
+//! The `encoding` feature adds hex and base64 conversions for
+//! `Scalar` and `CompressedEdwardsY`; see the [`encoding`] module.
+//!
+//! The `parallel` feature uses `rayon` to build the lookup tables for
+//! the dynamic points of a precomputed multiscalar multiplication on
+//! multiple threads; the multiplication itself stays serial.
+//!
+//! The `stats` feature adds
+//! `EdwardsPoint::vartime_multiscalar_mul_with_stats`, which reports
+//! the number of group operations a multiscalar multiplication used.
+//! It is only supported by the serial backends.
+//!
 //! # Safety
 //!
 //! The `curve25519-dalek` types are designed to make illegal states
@@ -161,6 +173,9 @@
 //! intrinsics.  These are marked `unsafe` only because invoking them on an
 //! inappropriate CPU would cause `SIGILL`, but the entire backend is only
 //! compiled with appropriate `target_feature`s, so this cannot occur.
+//! The one other exception is the optional `ffi` feature, whose
+//! `EdwardsBasepointTable::as_flat_field_limbs` reinterprets the table as
+//! a slice of field limbs.
 //!
 //! # Performance
 //!
@@ -269,6 +284,9 @@ extern crate zeroize;
 #[cfg(any(feature = "fiat_u64_backend", feature = "fiat_u32_backend"))]
 extern crate fiat_crypto;
 
+#[cfg(feature = "parallel")]
+extern crate rayon;
+
 // Used for traits related to constant-time code.
 extern crate subtle;
 
@@ -306,6 +324,17 @@ pub mod constants;
 // External (and internal) traits.
 pub mod traits;
 
+// A minimal transcript for Fiat-Shamir challenges
+pub mod challenge;
+
+// Building blocks for hashing to the curve
+#[cfg(feature = "alloc")]
+pub mod hash_to_curve;
+
+// Hex and base64 encodings of points and scalars
+#[cfg(feature = "encoding")]
+pub mod encoding;
+
 //------------------------------------------------------------------------
 // curve25519-dalek internal modules
 //------------------------------------------------------------------------
@@ -51,7 +51,7 @@
 
 use core::ops::{Mul, MulAssign};
 
-use constants::{APLUS2_OVER_FOUR, MONTGOMERY_A, MONTGOMERY_A_NEG};
+use constants::{APLUS2_OVER_FOUR, ED25519_BASEPOINT_TABLE, MONTGOMERY_A, MONTGOMERY_A_NEG};
 use edwards::{CompressedEdwardsY, EdwardsPoint};
 use field::FieldElement;
 use scalar::Scalar;
@@ -162,6 +162,80 @@ impl MontgomeryPoint {
 
         CompressedEdwardsY(y_bytes).decompress()
     }
+
+    /// Generate an X25519 keypair from 32 bytes of secret key material,
+    /// as specified in [RFC 7748][rfc7748].
+    ///
+    /// The secret key bytes are "clamped" (the low three bits and the
+    /// high bit are cleared, and bit 254 is set), and the public key
+    /// is the clamped scalar times the X25519 basepoint.
+    ///
+    /// # Return
+    ///
+    /// A tuple `(public_key, clamped)` where `public_key` is the byte
+    /// encoding of the public `MontgomeryPoint`, and `clamped` is the
+    /// clamped secret scalar, suitable for later Diffie-Hellman via
+    /// `clamped * their_public_key`.
+    ///
+    /// Note that the clamped scalar is **not** reduced modulo
+    /// \\( \ell \\), since that would change the result of the
+    /// Montgomery ladder on points of the twist.  It should only be
+    /// used for scalar multiplication, not for scalar arithmetic.
+    ///
+    /// [rfc7748]: https://tools.ietf.org/html/rfc7748#section-5
+    pub fn keygen(sk_bytes: &[u8; 32]) -> ([u8; 32], Scalar) {
+        let mut clamped_bytes = *sk_bytes;
+        clamped_bytes[0] &= 248;
+        clamped_bytes[31] &= 127;
+        clamped_bytes[31] |= 64;
+
+        let clamped = Scalar::from_bits(clamped_bytes);
+        clamped_bytes.zeroize();
+
+        let public = (&ED25519_BASEPOINT_TABLE * &clamped).to_montgomery();
+
+        (public.to_bytes(), clamped)
+    }
+
+    /// Add two `MontgomeryPoint`s by lifting them to the Edwards model,
+    /// adding there, and mapping the sum back to the \\(u\\)-line.
+    ///
+    /// # Inputs
+    ///
+    /// * `other`: the `MontgomeryPoint` to add to `self`;
+    ///
+    /// * `self_sign`, `other_sign`: the signs used to lift `self` and
+    ///   `other` to `EdwardsPoint`s, as in `to_edwards()`.
+    ///
+    /// # Return
+    ///
+    /// * `Some(MontgomeryPoint)` with the \\(u\\)-coordinate of the sum
+    ///   of the lifted points;
+    ///
+    /// * `None` if either `self` or `other` is the \\(u\\)-coordinate of a
+    ///   point on the twist.
+    ///
+    /// # Warning
+    ///
+    /// A \\(u\\)-coordinate only determines a point up to sign, so
+    /// \\(u(P)\\) and \\(u(Q)\\) determine \\(u(P+Q)\\) or \\(u(P-Q)\\)
+    /// depending on the choice of signs.  Callers must track the signs
+    /// of the points themselves for the result to be meaningful.
+    ///
+    /// This requires two decompressions and a compression, so it is
+    /// much slower than the Montgomery ladder, and it is not constant
+    /// time with respect to whether the inputs are on the curve.
+    pub fn add_via_edwards(
+        &self,
+        other: &MontgomeryPoint,
+        self_sign: u8,
+        other_sign: u8,
+    ) -> Option<MontgomeryPoint> {
+        let P = self.to_edwards(self_sign)?;
+        let Q = other.to_edwards(other_sign)?;
+
+        Some((P + Q).to_montgomery())
+    }
 }
 
 /// Perform the Elligator2 mapping to a Montgomery point.
@@ -429,6 +503,73 @@ mod test {
         assert!(minus_one.to_edwards(0).is_none());
     }
 
+    #[test]
+    fn add_via_edwards_matches_edwards_addition() {
+        let mut csprng: OsRng = OsRng;
+
+        let P = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut csprng);
+        let Q = &constants::ED25519_BASEPOINT_TABLE * &Scalar::random(&mut csprng);
+        let P_sign = P.sign();
+        let Q_sign = Q.sign();
+
+        let sum = P.to_montgomery()
+            .add_via_edwards(&Q.to_montgomery(), P_sign, Q_sign)
+            .unwrap();
+        assert_eq!(sum, (P + Q).to_montgomery());
+
+        // Flipping one sign gives the difference instead
+        let diff = P.to_montgomery()
+            .add_via_edwards(&Q.to_montgomery(), P_sign, Q_sign ^ 1)
+            .unwrap();
+        assert_eq!(diff, (P - Q).to_montgomery());
+
+        // u = 2 is on the twist
+        let one = FieldElement::one();
+        let two = MontgomeryPoint((&one+&one).to_bytes());
+        assert!(two.add_via_edwards(&P.to_montgomery(), 0, P_sign).is_none());
+    }
+
+    /// Test vectors from RFC 7748, section 6.1.
+    #[test]
+    fn keygen_rfc7748_vectors() {
+        let alice_sk: [u8; 32] = [
+            0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2, 0x66,
+            0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5, 0x1d, 0xb9,
+            0x2c, 0x2a,
+        ];
+        let alice_pk: [u8; 32] = [
+            0x85, 0x20, 0xf0, 0x09, 0x89, 0x30, 0xa7, 0x54, 0x74, 0x8b, 0x7d, 0xdc, 0xb4, 0x3e, 0xf7,
+            0x5a, 0x0d, 0xbf, 0x3a, 0x0d, 0x26, 0x38, 0x1a, 0xf4, 0xeb, 0xa4, 0xa9, 0x8e, 0xaa, 0x9b,
+            0x4e, 0x6a,
+        ];
+        let bob_sk: [u8; 32] = [
+            0x5d, 0xab, 0x08, 0x7e, 0x62, 0x4a, 0x8a, 0x4b, 0x79, 0xe1, 0x7f, 0x8b, 0x83, 0x80, 0x0e,
+            0xe6, 0x6f, 0x3b, 0xb1, 0x29, 0x26, 0x18, 0xb6, 0xfd, 0x1c, 0x2f, 0x8b, 0x27, 0xff, 0x88,
+            0xe0, 0xeb,
+        ];
+        let bob_pk: [u8; 32] = [
+            0xde, 0x9e, 0xdb, 0x7d, 0x7b, 0x7d, 0xc1, 0xb4, 0xd3, 0x5b, 0x61, 0xc2, 0xec, 0xe4, 0x35,
+            0x37, 0x3f, 0x83, 0x43, 0xc8, 0x5b, 0x78, 0x67, 0x4d, 0xad, 0xfc, 0x7e, 0x14, 0x6f, 0x88,
+            0x2b, 0x4f,
+        ];
+        let shared: [u8; 32] = [
+            0x4a, 0x5d, 0x9d, 0x5b, 0xa4, 0xce, 0x2d, 0xe1, 0x72, 0x8e, 0x3b, 0xf4, 0x80, 0x35, 0x0f,
+            0x25, 0xe0, 0x7e, 0x21, 0xc9, 0x47, 0xd1, 0x9e, 0x33, 0x76, 0xf0, 0x9b, 0x3c, 0x1e, 0x16,
+            0x17, 0x42,
+        ];
+
+        let (a_pk, a) = MontgomeryPoint::keygen(&alice_sk);
+        let (b_pk, b) = MontgomeryPoint::keygen(&bob_sk);
+        assert_eq!(a_pk, alice_pk);
+        assert_eq!(b_pk, bob_pk);
+
+        // The clamped scalar agrees with the ladder on the basepoint
+        assert_eq!((constants::X25519_BASEPOINT * a).to_bytes(), alice_pk);
+
+        assert_eq!((MontgomeryPoint(bob_pk) * a).to_bytes(), shared);
+        assert_eq!((MontgomeryPoint(alice_pk) * b).to_bytes(), shared);
+    }
+
     #[test]
     fn eq_defined_mod_p() {
         let mut u18_bytes = [0u8; 32]; u18_bytes[0] = 18;
@@ -14,6 +14,10 @@
 // TODO: switch to alloc::prelude
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 pub use alloc::vec::Vec;
+#[cfg(all(feature = "encoding", not(feature = "std")))]
+pub use alloc::string::String;
 
 #[cfg(feature = "std")]
 pub use std::vec::Vec;
+#[cfg(all(feature = "encoding", feature = "std"))]
+pub use std::string::String;
@@ -140,7 +140,7 @@
 //! **except for the highest bit, which will be set to 0**.
 
 use core::borrow::Borrow;
-use core::cmp::{Eq, PartialEq};
+use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
 use core::fmt::Debug;
 use core::iter::{Product, Sum};
 use core::ops::Index;
@@ -160,6 +160,7 @@ use digest::Digest;
 use subtle::Choice;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
 use zeroize::Zeroize;
 
@@ -189,6 +190,32 @@ type UnpackedScalar = backend::serial::u64::scalar::Scalar52;
 #[cfg(feature = "u32_backend")]
 type UnpackedScalar = backend::serial::u32::scalar::Scalar29;
 
+/// The limbs of a `Scalar` in Montgomery form, as used by the scalar
+/// arithmetic of the selected backend: five 52-bit limbs on 64-bit
+/// backends, nine 29-bit limbs on 32-bit backends, least significant
+/// first.
+///
+/// # Warning
+///
+/// This layout is backend-specific and **unstable**: it is only
+/// available with the `unstable_montgomery_limbs` feature, and it may
+/// change in any release, including patch releases.
+#[cfg(all(feature = "unstable_montgomery_limbs", any(feature = "u64_backend", feature = "fiat_u64_backend")))]
+pub type MontgomeryLimbs = [u64; 5];
+
+/// The limbs of a `Scalar` in Montgomery form, as used by the scalar
+/// arithmetic of the selected backend: five 52-bit limbs on 64-bit
+/// backends, nine 29-bit limbs on 32-bit backends, least significant
+/// first.
+///
+/// # Warning
+///
+/// This layout is backend-specific and **unstable**: it is only
+/// available with the `unstable_montgomery_limbs` feature, and it may
+/// change in any release, including patch releases.
+#[cfg(all(feature = "unstable_montgomery_limbs", any(feature = "u32_backend", feature = "fiat_u32_backend")))]
+pub type MontgomeryLimbs = [u32; 9];
+
 
 /// The `Scalar` struct holds an integer \\(s < 2\^{255} \\) which
 /// represents an element of \\(\mathbb Z / \ell\\).
@@ -223,12 +250,86 @@ impl Scalar {
         s
     }
 
+    /// Construct a `Scalar` by reducing a 256-bit **big-endian** integer
+    /// modulo the group order \\( \ell \\).
+    ///
+    /// This is `from_bytes_mod_order` applied to the reversed bytes, for
+    /// interoperability with systems which encode scalars big-endian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let mut bytes = [0u8; 32];
+    /// bytes[31] = 7;
+    ///
+    /// assert_eq!(Scalar::from_bytes_mod_order_be(bytes), Scalar::from(7u64));
+    /// ```
+    pub fn from_bytes_mod_order_be(bytes: [u8; 32]) -> Scalar {
+        let mut le_bytes = bytes;
+        le_bytes.reverse();
+
+        Scalar::from_bytes_mod_order(le_bytes)
+    }
+
     /// Construct a `Scalar` by reducing a 512-bit little-endian integer
     /// modulo the group order \\( \ell \\).
     pub fn from_bytes_mod_order_wide(input: &[u8; 64]) -> Scalar {
         UnpackedScalar::from_bytes_wide(input).pack()
     }
 
+    /// Construct a `Scalar` by reducing a 512-bit **big-endian** integer
+    /// modulo the group order \\( \ell \\).
+    ///
+    /// This is `from_bytes_mod_order_wide` applied to the reversed bytes:
+    /// `input[0]` is the most significant byte.
+    pub fn from_bytes_mod_order_wide_be(input: &[u8; 64]) -> Scalar {
+        let mut le_bytes = *input;
+        le_bytes.reverse();
+
+        Scalar::from_bytes_mod_order_wide(&le_bytes)
+    }
+
+    /// Construct a `Scalar` by reducing a 384-bit **big-endian** integer,
+    /// such as a SHA-384 digest, modulo the group order \\( \ell \\).
+    ///
+    /// The input is zero-extended to 512 bits, by prepending 16 zero
+    /// bytes, and reduced with `from_bytes_mod_order_wide_be`.  So the
+    /// result is the same as interpreting the 48 bytes as a big-endian
+    /// integer and reducing it; since \\( 2\^{384} \\) is much larger than
+    /// \\( \ell \\), it is close to uniform for uniform input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let mut bytes = [0u8; 48];
+    /// bytes[47] = 7;
+    ///
+    /// assert_eq!(Scalar::from_bytes_mod_order_384_be(&bytes), Scalar::from(7u64));
+    /// ```
+    pub fn from_bytes_mod_order_384_be(input: &[u8; 48]) -> Scalar {
+        let mut wide = [0u8; 64];
+        wide[16..].copy_from_slice(input);
+
+        Scalar::from_bytes_mod_order_wide_be(&wide)
+    }
+
+    /// Construct a challenge `Scalar` from 64 bytes of transcript output.
+    ///
+    /// This is exactly the conversion performed by Merlin's
+    /// `challenge_scalar`: the 64 bytes are interpreted as a 512-bit
+    /// little-endian integer and reduced modulo \\( \ell \\), as in
+    /// `from_bytes_mod_order_wide`.  Using this function instead of
+    /// `from_bytes_mod_order` (which would only use 32 of the bytes)
+    /// keeps the challenge distribution close to uniform and the
+    /// result compatible with Merlin-based protocols.
+    pub fn from_transcript_bytes(bytes: &[u8; 64]) -> Scalar {
+        Scalar::from_bytes_mod_order_wide(bytes)
+    }
+
     /// Attempt to construct a `Scalar` from a canonical byte representation.
     ///
     /// # Return
@@ -281,6 +382,28 @@ impl ConstantTimeEq for Scalar {
     }
 }
 
+/// Scalars are ordered by the integer value of their little-endian byte
+/// representation.
+///
+/// For canonical scalars, which is what all arithmetic produces, this is
+/// the order of their values in \\( [0, \ell) \\).  Unreduced scalars,
+/// e.g. from `Scalar::from_bits`, are ordered by their unreduced value,
+/// so that the order agrees with `==`.
+///
+/// The comparison is **not** constant time, and is meant for public
+/// scalars, e.g. for sorting or as `BTreeMap` keys.
+impl Ord for Scalar {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes.iter().rev().cmp(other.bytes.iter().rev())
+    }
+}
+
+impl PartialOrd for Scalar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Index<usize> for Scalar {
     type Output = u8;
 
@@ -534,12 +657,130 @@ impl From<u128> for Scalar {
     }
 }
 
+impl Scalar {
+    /// Construct a `Scalar` from a `u64`, in constant time.
+    ///
+    /// This gives the same result as `Scalar::from(x)`, but is
+    /// guaranteed to run in time independent of `x`, so that it can be
+    /// used on secret values, e.g. integer shares in an MPC protocol.
+    /// Since \\( x < 2\^{64} < \ell \\), no reduction is needed.
+    pub fn from_u64_ct(x: u64) -> Scalar {
+        let mut bytes = [0u8; 32];
+        for (i, b) in bytes[..8].iter_mut().enumerate() {
+            *b = (x >> (8 * i)) as u8;
+        }
+        Scalar{ bytes }
+    }
+
+    /// Construct a `Scalar` from a `u128`, in constant time.
+    ///
+    /// This gives the same result as `Scalar::from(x)`, but is
+    /// guaranteed to run in time independent of `x`; see `from_u64_ct`.
+    pub fn from_u128_ct(x: u128) -> Scalar {
+        let mut bytes = [0u8; 32];
+        for (i, b) in bytes[..16].iter_mut().enumerate() {
+            *b = (x >> (8 * i)) as u8;
+        }
+        Scalar{ bytes }
+    }
+}
+
 impl Zeroize for Scalar {
     fn zeroize(&mut self) {
         self.bytes.zeroize();
     }
 }
 
+#[cfg(feature = "std")]
+impl Scalar {
+    /// Read a `Scalar` from exactly 32 bytes of `reader`.
+    ///
+    /// If `strict` is `true`, the bytes must be the canonical encoding
+    /// of a scalar; otherwise they are reduced modulo \\( \ell \\), as
+    /// in `from_bytes_mod_order`.
+    ///
+    /// # Errors
+    ///
+    /// Any error from `read_exact` is passed through; an
+    /// [`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData)
+    /// error is returned if `strict` is set and the bytes are not
+    /// canonical.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(42u64);
+    /// let mut buf = Vec::new();
+    /// s.write_to(&mut buf).unwrap();
+    ///
+    /// assert_eq!(Scalar::read_from(&mut &buf[..], true).unwrap(), s);
+    /// ```
+    pub fn read_from<R: ::std::io::Read>(reader: &mut R, strict: bool) -> ::std::io::Result<Scalar> {
+        let mut bytes = [0u8; 32];
+        reader.read_exact(&mut bytes)?;
+
+        if !strict {
+            return Ok(Scalar::from_bytes_mod_order(bytes));
+        }
+        Scalar::from_canonical_bytes(bytes).ok_or_else(|| {
+            ::std::io::Error::new(::std::io::ErrorKind::InvalidData, "non-canonical scalar encoding")
+        })
+    }
+
+    /// Write the 32-byte canonical encoding of this `Scalar` to `writer`.
+    pub fn write_to<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl Scalar {
+    /// Decode a `Scalar` from the hex encoding of its canonical bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DecodingError` if `s` is not 64 hex digits, or if the
+    /// decoded bytes are not a canonical scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(255u64);
+    /// let hex = s.to_hex();
+    /// assert!(hex.starts_with("ff00"));
+    /// assert_eq!(Scalar::from_hex(&hex), Ok(s));
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Scalar, ::encoding::DecodingError> {
+        let bytes = ::encoding::decode_hex(s)?;
+        Scalar::from_canonical_bytes(bytes).ok_or(::encoding::DecodingError::NonCanonicalScalar)
+    }
+
+    /// Encode the canonical bytes of this `Scalar` as lowercase hex.
+    pub fn to_hex(&self) -> String {
+        ::encoding::encode_hex(self.as_bytes())
+    }
+
+    /// Decode a `Scalar` from the base64 encoding of its canonical bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DecodingError` if `s` is not a 44-character padded
+    /// base64 string, or if the decoded bytes are not a canonical scalar.
+    pub fn from_base64(s: &str) -> Result<Scalar, ::encoding::DecodingError> {
+        let bytes = ::encoding::decode_base64(s)?;
+        Scalar::from_canonical_bytes(bytes).ok_or(::encoding::DecodingError::NonCanonicalScalar)
+    }
+
+    /// Encode the canonical bytes of this `Scalar` as padded base64.
+    pub fn to_base64(&self) -> String {
+        ::encoding::encode_base64(self.as_bytes())
+    }
+}
+
 impl Scalar {
     /// Return a `Scalar` chosen uniformly at random using a user-provided RNG.
     ///
@@ -644,6 +885,127 @@ impl Scalar {
         Scalar::from_bytes_mod_order_wide(&output)
     }
 
+    /// Hash `prefix || msg` to a scalar, as in the deterministic nonce
+    /// derivation of Ed25519 signing.
+    ///
+    /// [RFC 8032][rfc8032] computes the nonce as
+    /// \\( r = H(\mathrm{prefix} \| M) \bmod \ell \\), where
+    /// \\( \mathrm{prefix} \\) is the second half of the hashed secret
+    /// key, and \\( M \\) is the message.
+    ///
+    /// Takes a type parameter `D`, which is any `Digest` producing 64
+    /// bytes (512 bits) of output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// extern crate sha2;
+    ///
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let prefix = [0x2au8; 32];
+    /// let r = Scalar::from_hash_prefixed::<Sha512>(&prefix, b"message");
+    ///
+    /// let mut concatenated = prefix.to_vec();
+    /// concatenated.extend_from_slice(b"message");
+    /// assert_eq!(r, Scalar::hash_from_bytes::<Sha512>(&concatenated));
+    /// # }
+    /// ```
+    ///
+    /// [rfc8032]: https://tools.ietf.org/html/rfc8032#section-5.1.6
+    pub fn from_hash_prefixed<D>(prefix: &[u8], msg: &[u8]) -> Scalar
+        where D: Digest<OutputSize = U64> + Default
+    {
+        let mut hash = D::default();
+        hash.update(prefix);
+        hash.update(msg);
+        Scalar::from_hash(hash)
+    }
+
+    /// Feed a vector of scalars into `hasher` as a single framed item,
+    /// for absorbing it into a Fiat-Shamir transcript.
+    ///
+    /// The frame is the total length of the encodings, \\( 32n \\) for
+    /// \\( n \\) scalars, as an 8-byte little-endian `u64`, followed by
+    /// the 32 bytes of each scalar, as returned by `as_bytes`, in slice
+    /// order.  The bytes are absorbed as they are, without reducing the
+    /// scalars modulo \\( \ell \\) first.
+    /// This is the framing used by [`ChallengeHasher`], so that
+    /// appending the vector is the same as appending the concatenation
+    /// of the encodings as one byte string, and the number of scalars
+    /// can be recovered from the frame: vectors of different lengths
+    /// are never absorbed as the same bytes.
+    ///
+    /// [`ChallengeHasher`]: ::challenge::ChallengeHasher
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// extern crate sha2;
+    ///
+    /// use sha2::{Digest, Sha512};
+    ///
+    /// # fn main() {
+    /// let scalars = [Scalar::from(1u64), Scalar::from(2u64)];
+    ///
+    /// let mut hasher = Sha512::new();
+    /// Scalar::hash_many(&scalars, &mut hasher);
+    ///
+    /// let mut expected = Sha512::new();
+    /// expected.update(64u64.to_le_bytes());
+    /// expected.update(scalars[0].as_bytes());
+    /// expected.update(scalars[1].as_bytes());
+    /// assert_eq!(hasher.finalize(), expected.finalize());
+    /// # }
+    /// ```
+    pub fn hash_many<D: Digest>(scalars: &[Scalar], hasher: &mut D) {
+        hasher.update(((32 * scalars.len()) as u64).to_le_bytes());
+        for s in scalars {
+            hasher.update(s.as_bytes());
+        }
+    }
+
+    /// Derive the `counter`-th scalar of a deterministic sequence from
+    /// `seed`, as \\( H(\mathrm{seed} \| \mathrm{counter}) \bmod \ell \\),
+    /// with the counter encoded as an 8-byte little-endian `u64`.
+    ///
+    /// Takes a type parameter `D`, which is any `Digest` producing 64
+    /// bytes (512 bits) of output, such as `Sha512`.
+    ///
+    /// The output depends only on the seed and counter, so the same
+    /// sequence is produced on every platform and backend.  That makes
+    /// it convenient for reproducible test fixtures, but the scalars are
+    /// only as secret as the seed: do not use them as secret keys or
+    /// nonces unless the seed is itself a uniformly random secret.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// extern crate sha2;
+    ///
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let seed = [7u8; 32];
+    /// let a = Scalar::derive_from_seed::<Sha512>(&seed, 0);
+    /// let b = Scalar::derive_from_seed::<Sha512>(&seed, 1);
+    /// assert_ne!(a, b);
+    /// assert_eq!(a, Scalar::derive_from_seed::<Sha512>(&seed, 0));
+    /// # }
+    /// ```
+    pub fn derive_from_seed<D>(seed: &[u8; 32], counter: u64) -> Scalar
+        where D: Digest<OutputSize = U64> + Default
+    {
+        Scalar::from_hash_prefixed::<D>(seed, &counter.to_le_bytes())
+    }
+
     /// Convert this `Scalar` to its underlying sequence of bytes.
     ///
     /// # Example
@@ -659,6 +1021,27 @@ impl Scalar {
         self.bytes
     }
 
+    /// Convert this `Scalar` to a **big-endian** sequence of bytes.
+    ///
+    /// This is the reverse of `to_bytes()`, which is little-endian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s: Scalar = Scalar::from(7u64);
+    ///
+    /// assert_eq!(s.to_bytes_be()[31], 7);
+    /// assert_eq!(s.to_bytes()[0], 7);
+    /// ```
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let mut bytes = self.bytes;
+        bytes.reverse();
+
+        bytes
+    }
+
     /// View the little-endian byte encoding of the integer representing this Scalar.
     ///
     /// # Example
@@ -674,6 +1057,102 @@ impl Scalar {
         &self.bytes
     }
 
+    /// Encode this `Scalar` in a variable-length form, which is shorter
+    /// than 32 bytes for small values.
+    ///
+    /// A scalar below \\( 2\^{224} \\) is encoded as the unsigned LEB128
+    /// encoding of its integer value, with no trailing zero groups: 7
+    /// bits per byte, least significant group first, and the high bit
+    /// of each byte set when another byte follows.  This takes 1 byte
+    /// for values below 128, 2 bytes below \\( 2\^{14} \\), and so on,
+    /// up to 32 bytes.
+    ///
+    /// Any larger scalar, which includes almost all random ones, is
+    /// encoded as the flag byte `0xff` followed by its 32-byte canonical
+    /// encoding, for 33 bytes in total.  Since a LEB128 encoding never
+    /// reaches 33 bytes, the length tells the two forms apart.
+    ///
+    /// The encoding is not self-delimiting in the second form, so
+    /// several encodings stored together need their own framing.  Its
+    /// length, and the time taken to compute it, depend on the value of
+    /// the scalar, so this should not be used for secret scalars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// assert_eq!(Scalar::from(5u64).to_varint(), vec![0x05]);
+    /// assert_eq!(Scalar::from(300u64).to_varint(), vec![0xac, 0x02]);
+    /// assert_eq!(Scalar::from_varint(&[0xac, 0x02]), Some(Scalar::from(300u64)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_varint(&self) -> Vec<u8> {
+        let bit_len = (0..256)
+            .rev()
+            .find(|&i| (self.bytes[i >> 3] >> (i & 7)) & 1 == 1)
+            .map_or(0, |i| i + 1);
+
+        let num_bytes = core::cmp::max((bit_len + 6) / 7, 1);
+        if num_bytes > 32 {
+            let mut out = Vec::with_capacity(33);
+            out.push(0xff);
+            out.extend_from_slice(&self.bytes);
+            return out;
+        }
+
+        let mut out = Vec::with_capacity(num_bytes);
+        for j in 0..num_bytes {
+            let mut group = 0u8;
+            for k in 0..7 {
+                let i = 7 * j + k;
+                group |= ((self.bytes[i >> 3] >> (i & 7)) & 1) << k;
+            }
+            if j + 1 < num_bytes {
+                group |= 0x80;
+            }
+            out.push(group);
+        }
+        out
+    }
+
+    /// Decode a `Scalar` from the variable-length encoding produced by
+    /// `to_varint`.
+    ///
+    /// Returns `None` unless `bytes` is exactly the encoding of a
+    /// scalar: a minimal LEB128 encoding of at most 32 bytes, or the
+    /// flag byte `0xff` followed by the canonical encoding of a scalar
+    /// which is at least \\( 2\^{224} \\).
+    pub fn from_varint(bytes: &[u8]) -> Option<Scalar> {
+        if bytes.len() == 33 && bytes[0] == 0xff {
+            let mut full = [0u8; 32];
+            full.copy_from_slice(&bytes[1..]);
+            // Smaller scalars have a shorter, LEB128 encoding.
+            if full[28..] == [0u8; 4] {
+                return None;
+            }
+            return Scalar::from_canonical_bytes(full);
+        }
+
+        let (last, init) = bytes.split_last()?;
+        if bytes.len() > 32
+            || last & 0x80 != 0
+            || init.iter().any(|b| b & 0x80 == 0)
+            || (*last == 0 && !init.is_empty())
+        {
+            return None;
+        }
+
+        let mut out = [0u8; 32];
+        for (j, group) in bytes.iter().enumerate() {
+            for k in 0..7 {
+                let i = 7 * j + k;
+                out[i >> 3] |= ((group >> k) & 1) << (i & 7);
+            }
+        }
+        Scalar::from_canonical_bytes(out)
+    }
+
     /// Construct the scalar \\( 0 \\).
     pub fn zero() -> Self {
         Scalar { bytes: [0u8; 32]}
@@ -730,20 +1209,163 @@ impl Scalar {
         self.unpack().invert().pack()
     }
 
-    /// Given a slice of nonzero (possibly secret) `Scalar`s,
-    /// compute their inverses in a batch.
+    /// Compute the multiplicative inverse of this `Scalar` as
+    /// \\( x\^{\ell - 2} \\), using plain left-to-right square-and-multiply
+    /// over the fixed bits of \\( \ell - 2 \\).
+    ///
+    /// This gives the same result as `invert`, which uses a shorter
+    /// addition chain for the same exponent, but is simpler to audit.
+    /// It always costs 256 Montgomery squarings and 73 Montgomery
+    /// multiplications (one per set bit of \\( \ell - 2 \\)), on every
+    /// backend, and is constant-time regardless of the input.
+    ///
+    /// As with `invert`, the input must be nonzero for the result to be
+    /// an inverse; a zero input gives zero.
+    pub fn invert_fermat(&self) -> Scalar {
+        const L_MINUS_2: [u8; 32] = [
+            0xeb, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58,
+            0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+        ];
+
+        self.unpack()
+            .to_montgomery()
+            .montgomery_pow(&L_MINUS_2)
+            .from_montgomery()
+            .pack()
+    }
+
+    /// Compute \\( -(x\^{-1}) \\) for this `Scalar` \\( x \\), for instance
+    /// to unblind an OPRF evaluation.
     ///
-    /// # Return
+    /// This runs in constant time, and is just `-self.invert()`, except
+    /// that it checks the input: unlike `invert`, which returns zero for
+    /// a zero input, this returns `None` when `self` is zero mod
+    /// \\( \ell \\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
     ///
-    /// Each element of `inputs` is replaced by its inverse.
+    /// let r = Scalar::from(5u64);
+    /// assert_eq!(r.neg_invert().unwrap() * r, -Scalar::one());
+    /// assert!(bool::from(Scalar::zero().neg_invert().is_none()));
+    /// ```
+    pub fn neg_invert(&self) -> CtOption<Scalar> {
+        let is_nonzero = !self.reduce().ct_eq(&Scalar::zero());
+        CtOption::new(-self.invert(), is_nonzero)
+    }
+
+    /// Compute the geometric sum
+    /// \\( 1 + x + x\^2 + \cdots + x\^{n-1} \\) for this `Scalar` \\( x \\).
     ///
-    /// The product of all inverses is returned.
+    /// This uses the closed form \\( (x\^n - 1)/(x - 1) \\), so it costs
+    /// \\( O(\log n) \\) multiplications and one inversion, rather than
+    /// the \\( n \\) multiplications of summing the powers.  When
+    /// \\( x = 1 \\) the sum is \\( n \\); that case is selected in
+    /// constant time, so the running time depends only on `n`.
     ///
-    /// # Warning
+    /// # Example
     ///
-    /// All input `Scalars` **MUST** be nonzero.  If you cannot
-    /// *prove* that this is the case, you **SHOULD NOT USE THIS
-    /// FUNCTION**.
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// // 1 + 2 + 4 + 8 = 15
+    /// assert_eq!(Scalar::from(2u64).geometric_sum(4), Scalar::from(15u64));
+    /// assert_eq!(Scalar::one().geometric_sum(4), Scalar::from(4u64));
+    /// ```
+    pub fn geometric_sum(&self, n: u64) -> Scalar {
+        let one = Scalar::one();
+
+        let mut x_n = one;
+        for i in (0..(64 - n.leading_zeros())).rev() {
+            x_n = x_n * x_n;
+            if (n >> i) & 1 == 1 {
+                x_n *= self;
+            }
+        }
+
+        let x_minus_one = self - one;
+        let closed_form = (x_n - one) * x_minus_one.invert();
+        let is_one = x_minus_one.ct_eq(&Scalar::zero());
+        Scalar::conditional_select(&closed_form, &Scalar::from(n), is_one)
+    }
+
+    /// Compute a square root of this `Scalar` modulo \\( \ell \\).
+    ///
+    /// Since \\( \ell \\) is prime, \\( \mathbb Z / \ell \\) is a field,
+    /// and every nonzero quadratic residue has exactly two square roots
+    /// \\( \pm r \\).  No guarantee is made about which of the two is
+    /// returned.
+    ///
+    /// This function runs in constant time.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(r)` with \\( r\^2 = \\) `self` if `self` is a square
+    ///   (including zero);
+    ///
+    /// * `None` if `self` is a quadratic nonresidue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let r = Scalar::from(9u64).sqrt().unwrap();
+    /// assert!(r == Scalar::from(3u64) || r == -Scalar::from(3u64));
+    ///
+    /// // 2 is not a square mod l
+    /// assert!(bool::from(Scalar::from(2u64).sqrt().is_none()));
+    /// ```
+    pub fn sqrt(&self) -> CtOption<Scalar> {
+        // Since \ell = 5 (mod 8), we can use Atkin's method: with
+        //
+        //     v = (2a)^((\ell-5)/8),   i = 2a v^2,
+        //
+        // i is a square root of -1 when a is a square, and
+        // x = a v (i - 1) satisfies x^2 = a.
+        //
+        // (\ell - 5)/8, little-endian
+        const L_MINUS_5_OVER_8: [u8; 32] = [
+            0x7d, 0xba, 0x9e, 0x4b, 0x63, 0x4c, 0x02, 0xcb,
+            0x9a, 0xf3, 0x5e, 0xd4, 0x3b, 0xdf, 0x9b, 0x02,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        ];
+
+        let a = self.unpack().to_montgomery();
+        let a2 = UnpackedScalar::add(&a, &a);
+        let v = a2.montgomery_pow(&L_MINUS_5_OVER_8);
+        let i = UnpackedScalar::montgomery_mul(&a2, &v.montgomery_square());
+        let i_minus_one = UnpackedScalar::sub(&i, &constants::R);
+        let av = UnpackedScalar::montgomery_mul(&a, &v);
+        let x = UnpackedScalar::montgomery_mul(&av, &i_minus_one)
+            .from_montgomery()
+            .pack();
+
+        let is_square = (x * x).ct_eq(&self.reduce());
+
+        CtOption::new(x, is_square)
+    }
+
+    /// Given a slice of (possibly secret) `Scalar`s, compute their
+    /// inverses in a batch.
+    ///
+    /// # Return
+    ///
+    /// Each nonzero element of `inputs` is replaced by its inverse.
+    /// Each zero element is left as zero, i.e. zero is treated as its
+    /// own "inverse".
+    ///
+    /// The product of the inverses of all the nonzero elements is
+    /// returned; zero elements are excluded from the product, so if
+    /// every element is zero (or `inputs` is empty), one is returned.
+    ///
+    /// Which elements are zero is never revealed through timing: the
+    /// zero entries are handled with constant-time selections.
     ///
     /// # Example
     ///
@@ -789,19 +1411,29 @@ impl Scalar {
         // Keep an accumulator of all of the previous products
         let mut acc = Scalar::one().unpack().to_montgomery();
 
+        // Remember which inputs are zero, so that we can substitute
+        // one for them and restore them at the end.
+        let mut zero_flags = Zeroizing::new(vec![0u8; n]);
+        let one_packed = one.pack();
+
         // Pass through the input vector, recording the previous
         // products in the scratch space
-        for (input, scratch) in inputs.iter_mut().zip(scratch.iter_mut()) {
+        let forward = inputs.iter_mut().zip(scratch.iter_mut()).zip(zero_flags.iter_mut());
+        for ((input, scratch), is_zero) in forward {
             *scratch = acc;
 
             // Avoid unnecessary Montgomery multiplication in second pass by
             // keeping inputs in Montgomery form
-            let tmp = input.unpack().to_montgomery();
-            *input = tmp.pack();
-            acc = UnpackedScalar::montgomery_mul(&acc, &tmp);
+            let mut tmp = input.unpack().to_montgomery().pack();
+            let input_is_zero = tmp.ct_eq(&Scalar::zero());
+            tmp.conditional_assign(&one_packed, input_is_zero);
+            *is_zero = input_is_zero.unwrap_u8();
+
+            *input = tmp;
+            acc = UnpackedScalar::montgomery_mul(&acc, &tmp.unpack());
         }
 
-        // acc is nonzero iff all inputs are nonzero
+        // acc is the product of the nonzero inputs, so it is nonzero
         debug_assert!(acc.pack() != Scalar::zero());
 
         // Compute the inverse of all products
@@ -812,13 +1444,72 @@ impl Scalar {
 
         // Pass through the vector backwards to compute the inverses
         // in place
-        for (input, scratch) in inputs.iter_mut().rev().zip(scratch.iter().rev()) {
+        let backward = inputs.iter_mut().rev().zip(scratch.iter().rev()).zip(zero_flags.iter().rev());
+        for ((input, scratch), is_zero) in backward {
             let tmp = UnpackedScalar::montgomery_mul(&acc, &input.unpack());
             *input = UnpackedScalar::montgomery_mul(&acc, &scratch).pack();
+            input.conditional_assign(&Scalar::zero(), Choice::from(*is_zero));
             acc = tmp;
         }
 
-        ret
+        ret
+    }
+
+    /// Compute the Lagrange coefficients at \\( x = 0 \\) for the
+    /// interpolation points `indices`.
+    ///
+    /// The \\( i \\)-th coefficient is
+    /// $$
+    /// \lambda\_i = \prod\_{j \neq i} \frac{x\_j}{x\_j - x\_i},
+    /// $$
+    /// so that \\( f(0) = \sum\_i \lambda\_i f(x\_i) \\) for every
+    /// polynomial \\( f \\) of degree less than `indices.len()`, as
+    /// needed to reconstruct a secret from Shamir shares or to combine
+    /// threshold signature shares.  The numerators and denominators are
+    /// computed with the product formula, in \\( O(n\^2) \\)
+    /// multiplications, and all the denominators are inverted at once with
+    /// `batch_invert`.
+    ///
+    /// # Panics
+    ///
+    /// If `indices` contains the same index twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// # fn main() {
+    /// // Shares of f(x) = 5 + 3x at x = 1 and x = 2.
+    /// let shares = [Scalar::from(8u64), Scalar::from(11u64)];
+    /// let lambdas = Scalar::lagrange_coefficients(&[1, 2]);
+    ///
+    /// let secret: Scalar = lambdas.iter().zip(shares.iter()).map(|(l, y)| l * y).sum();
+    /// assert_eq!(secret, Scalar::from(5u64));
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn lagrange_coefficients(indices: &[u64]) -> Vec<Scalar> {
+        let xs: Vec<Scalar> = indices.iter().map(|&x| Scalar::from(x)).collect();
+
+        let mut numerators = vec![Scalar::one(); xs.len()];
+        let mut denominators = vec![Scalar::one(); xs.len()];
+        for (i, x_i) in xs.iter().enumerate() {
+            for (j, x_j) in xs.iter().enumerate() {
+                if i != j {
+                    assert!(indices[i] != indices[j], "duplicate Lagrange interpolation index");
+                    numerators[i] *= x_j;
+                    denominators[i] *= x_j - x_i;
+                }
+            }
+        }
+
+        Scalar::batch_invert(&mut denominators);
+        numerators
+            .iter()
+            .zip(denominators.iter())
+            .map(|(n, d)| n * d)
+            .collect()
     }
 
     /// Get the bits of the scalar.
@@ -904,11 +1595,44 @@ impl Scalar {
     /// If \\( k \mod 2^w\\) is even, we emit \\(0\\), advance 1 bit
     /// and reindex.  In fact, by setting all digits to \\(0\\)
     /// initially, we don't need to emit anything.
-    pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
-        // required by the NAF definition
-        debug_assert!( w >= 2 );
-        // required so that the NAF digits fit in i8
-        debug_assert!( w <= 8 );
+    ///
+    /// # Return
+    ///
+    /// The digits \\(n\_0, \ldots, n\_{255}\\), where `naf[i]` is the
+    /// coefficient of \\(2\^i\\) and the digits above the length of the
+    /// NAF are zero.  The NAF is of the integer held by the scalar, which
+    /// is not reduced modulo \\(\ell\\) if the scalar was not.
+    ///
+    /// # Panics
+    ///
+    /// If `w` is not between 2 and 8, inclusive: the NAF needs
+    /// \\(w \geq 2\\), and the digits only fit in an `i8` for
+    /// \\(w \leq 8\\).
+    ///
+    /// # Warning
+    ///
+    /// The computation branches on the digits, so it is **not**
+    /// constant-time, and should only be used on public scalars, as in
+    /// the variable-time multiscalar multiplications.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// // 7 = 8 - 1 = 2^3 - 2^0 as a width-2 NAF
+    /// let naf = Scalar::from(7u64).non_adjacent_form(2);
+    /// assert_eq!(&naf[..4], &[-1, 0, 0, 1]);
+    /// assert!(naf[4..].iter().all(|&d| d == 0));
+    ///
+    /// // Digits of a width-5 NAF are odd and less than 16 in absolute value
+    /// let naf = Scalar::from(987654321u64).non_adjacent_form(5);
+    /// assert!(naf.iter().all(|&d| d == 0 || (d % 2 != 0 && d.abs() < 16)));
+    /// ```
+    pub fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
+        // required by the NAF definition, and so that the NAF digits
+        // fit in i8
+        assert!((2..=8).contains(&w), "NAF width must be between 2 and 8");
 
         use byteorder::{ByteOrder, LittleEndian};
 
@@ -1129,6 +1853,89 @@ impl Scalar {
     pub fn is_canonical(&self) -> bool {
         *self == self.reduce()
     }
+
+    /// Check whether the canonical value of this scalar, i.e., its value
+    /// reduced modulo \\( \ell \\), is equal to `x`.
+    ///
+    /// This gives the same result as `self.reduce() == Scalar::from(x)`,
+    /// without constructing a second scalar, and only reduces `self` if
+    /// it is at least \\( 2\^{252} \\).  It is **variable-time**, and is
+    /// intended for comparisons with public values, e.g. in debugging or
+    /// input validation.
+    pub fn equals_u64(&self, x: u64) -> bool {
+        // Values below 2^252 are less than l, so they are already reduced.
+        let bytes = if self.bytes[31] >> 4 == 0 { self.bytes } else { self.reduce().bytes };
+
+        let mut low = [0u8; 8];
+        low.copy_from_slice(&bytes[..8]);
+        bytes[8..].iter().all(|&b| b == 0) && u64::from_le_bytes(low) == x
+    }
+
+    /// Check whether the canonical value of this scalar, i.e., its value
+    /// reduced modulo \\( \ell \\), is less than \\( 2\^n \\).
+    ///
+    /// This is constant-time in the value of the scalar, but not in
+    /// `n`, which is assumed to be public.
+    pub fn ct_fits_in_bits(&self, n: u32) -> Choice {
+        let bytes = self.reduce().bytes;
+
+        // OR together all the bits at positions n and above
+        let mut high_bits = 0u8;
+        for (i, byte) in bytes.iter().enumerate() {
+            let first_bit = 8 * i as u32;
+            let mask = if n <= first_bit {
+                0xff
+            } else if n >= first_bit + 8 {
+                0
+            } else {
+                0xffu8 << (n - first_bit)
+            };
+            high_bits |= byte & mask;
+        }
+
+        high_bits.ct_eq(&0)
+    }
+
+    /// Get the limbs of the Montgomery form \\( sR \bmod \ell \\) of this
+    /// scalar, where \\( R \\) is the Montgomery modulus of the backend.
+    ///
+    /// # Warning
+    ///
+    /// The layout of `MontgomeryLimbs`, and the value of \\( R \\), are
+    /// backend-specific and **unstable**.  This is intended only for
+    /// performance-critical code which does its own arithmetic on the
+    /// internal representation.
+    #[cfg(feature = "unstable_montgomery_limbs")]
+    pub fn to_montgomery_limbs(&self) -> MontgomeryLimbs {
+        self.unpack().to_montgomery().0
+    }
+
+    /// Construct a `Scalar` from the limbs of its Montgomery form, as
+    /// returned by `to_montgomery_limbs`.
+    ///
+    /// # Return
+    ///
+    /// - `Some(s)` if every limb is within the backend's limb size and
+    ///   the limbs represent an integer less than \\( \ell \\);
+    /// - `None` otherwise.
+    ///
+    /// # Warning
+    ///
+    /// The layout of `MontgomeryLimbs` is backend-specific and
+    /// **unstable**; see `to_montgomery_limbs`.
+    #[cfg(feature = "unstable_montgomery_limbs")]
+    pub fn from_montgomery_limbs(limbs: MontgomeryLimbs) -> CtOption<Scalar> {
+        // Packing and unpacking the limbs round-trips exactly when every
+        // limb is in range and the value fits in 256 bits.
+        let mut candidate = UnpackedScalar::zero();
+        candidate.0 = limbs;
+        let packed = candidate.pack();
+        let unpacked = packed.unpack();
+        let is_in_range = unpacked.0[..].ct_eq(&limbs[..]);
+        let is_canonical = packed.ct_eq(&packed.reduce());
+
+        CtOption::new(unpacked.from_montgomery().pack(), is_in_range & is_canonical)
+    }
 }
 
 impl UnpackedScalar {
@@ -1193,12 +2000,148 @@ impl UnpackedScalar {
         y
     }
 
+    /// Raises an UnpackedScalar in Montgomery form to the power
+    /// `exp`, given as little-endian bytes.
+    ///
+    /// The running time depends only on `exp`, which is assumed public.
+    fn montgomery_pow(&self, exp: &[u8; 32]) -> UnpackedScalar {
+        let mut y = constants::R;
+
+        for i in (0..256).rev() {
+            y = y.montgomery_square();
+            if (exp[i >> 3] >> (i & 7)) & 1 == 1 {
+                y = UnpackedScalar::montgomery_mul(&y, self);
+            }
+        }
+
+        y
+    }
+
     /// Inverts an UnpackedScalar not in Montgomery form.
     pub fn invert(&self) -> UnpackedScalar {
         self.to_montgomery().montgomery_invert().from_montgomery()
     }
 }
 
+/// A 512-bit integer which has not been reduced modulo \\( \ell \\),
+/// such as the product of two scalars computed by `Scalar::mul_wide`.
+///
+/// Products can be accumulated with `+` and reduced once at the end
+/// with `reduce()`, instead of reducing after every multiplication.
+/// Since \\( \ell < 2\^{253} \\), the product of two canonical
+/// scalars is less than \\( 2\^{506} \\), so at least \\( 64 \\)
+/// such products can be summed without overflow.  Scalars built with
+/// `Scalar::from_bits` can be as large as \\( 2\^{255} \\), and only
+/// \\( 4 \\) of their products are guaranteed to fit.
+///
+/// Addition wraps modulo \\( 2\^{512} \\) on overflow, which gives a
+/// wrong result after reduction; debug builds panic instead.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct UnreducedScalar {
+    /// Little-endian 64-bit limbs.
+    limbs: [u64; 8],
+}
+
+impl UnreducedScalar {
+    /// The unreduced integer zero.
+    pub fn zero() -> UnreducedScalar {
+        UnreducedScalar { limbs: [0u64; 8] }
+    }
+
+    /// Reduce this integer modulo \\( \ell \\).
+    pub fn reduce(&self) -> Scalar {
+        Scalar::from_bytes_mod_order_wide(&self.to_bytes())
+    }
+
+    /// Convert this integer to its 64-byte little-endian representation.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        use byteorder::{ByteOrder, LittleEndian};
+
+        let mut bytes = [0u8; 64];
+        LittleEndian::write_u64_into(&self.limbs, &mut bytes);
+        bytes
+    }
+}
+
+impl Scalar {
+    /// Compute the full 512-bit product of `self` and `other`, without
+    /// reducing it modulo \\( \ell \\).
+    ///
+    /// See `UnreducedScalar` for how many products can be accumulated
+    /// before reducing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::{Scalar, UnreducedScalar};
+    ///
+    /// let a = [Scalar::from(3u64), Scalar::from(5u64)];
+    /// let b = [Scalar::from(7u64), -Scalar::one()];
+    ///
+    /// let mut acc = UnreducedScalar::zero();
+    /// for (a_i, b_i) in a.iter().zip(b.iter()) {
+    ///     acc += a_i.mul_wide(b_i);
+    /// }
+    ///
+    /// assert_eq!(acc.reduce(), Scalar::from(16u64));
+    /// ```
+    pub fn mul_wide(&self, other: &Scalar) -> UnreducedScalar {
+        use byteorder::{ByteOrder, LittleEndian};
+
+        let mut a = [0u64; 4];
+        let mut b = [0u64; 4];
+        LittleEndian::read_u64_into(&self.bytes, &mut a);
+        LittleEndian::read_u64_into(&other.bytes, &mut b);
+
+        // Schoolbook multiplication; each step computes
+        // limbs[i + j] + a[i] * b[j] + carry < 2^128.
+        let mut limbs = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let t = (limbs[i + j] as u128) + (a[i] as u128) * (b[j] as u128) + carry;
+                limbs[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            limbs[i + 4] = carry as u64;
+        }
+
+        UnreducedScalar { limbs }
+    }
+}
+
+impl<'b> Add<&'b UnreducedScalar> for &UnreducedScalar {
+    type Output = UnreducedScalar;
+    fn add(self, _rhs: &'b UnreducedScalar) -> UnreducedScalar {
+        let mut limbs = [0u64; 8];
+        let mut carry = 0u128;
+        for (limb, (x, y)) in limbs.iter_mut().zip(self.limbs.iter().zip(_rhs.limbs.iter())) {
+            let t = (*x as u128) + (*y as u128) + carry;
+            *limb = t as u64;
+            carry = t >> 64;
+        }
+        debug_assert_eq!(carry, 0, "UnreducedScalar addition overflowed");
+
+        UnreducedScalar { limbs }
+    }
+}
+
+define_add_variants!(LHS = UnreducedScalar, RHS = UnreducedScalar, Output = UnreducedScalar);
+
+impl<'b> AddAssign<&'b UnreducedScalar> for UnreducedScalar {
+    fn add_assign(&mut self, _rhs: &'b UnreducedScalar) {
+        *self = *self + _rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = UnreducedScalar, RHS = UnreducedScalar);
+
+impl Zeroize for UnreducedScalar {
+    fn zeroize(&mut self) {
+        self.limbs.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1367,6 +2310,12 @@ mod test {
         }
     }
 
+    #[test]
+    #[should_panic]
+    fn non_adjacent_form_rejects_wide_windows() {
+        A_SCALAR.non_adjacent_form(9);
+    }
+
     #[test]
     fn from_u64() {
         let val: u64 = 0xdeadbeefdeadbeef;
@@ -1557,6 +2506,104 @@ mod test {
         assert_eq!(biggest, CANONICAL_2_256_MINUS_1);
     }
 
+    #[test]
+    fn from_transcript_bytes() {
+        // bytes = 0x00, 0x01, ..., 0x3f, reduced as a 512-bit
+        // little-endian integer mod l
+        let mut transcript_bytes = [0u8; 64];
+        for (i, byte) in transcript_bytes.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let expected = Scalar{
+            bytes: [
+                0x7a, 0x3c, 0x62, 0x82, 0xf0, 0x2d, 0x37, 0xa0,
+                0x50, 0x23, 0xb6, 0x0d, 0x54, 0x28, 0xe6, 0xcc,
+                0x59, 0x61, 0xd4, 0xc3, 0x12, 0x21, 0x93, 0x7a,
+                0xda, 0xe0, 0xb5, 0x74, 0xe4, 0xd0, 0x72, 0x05,
+            ],
+        };
+        let challenge = Scalar::from_transcript_bytes(&transcript_bytes);
+        assert_eq!(challenge, expected);
+        assert_eq!(challenge, Scalar::from_bytes_mod_order_wide(&transcript_bytes));
+    }
+
+    /// Check the nonces of the RFC 8032 Ed25519 test vectors, by
+    /// recomputing the first half \\( R = rB \\) of each signature.
+    #[test]
+    #[allow(non_snake_case)]
+    fn from_hash_prefixed_rfc8032_nonces() {
+        // (secret key, message, encoding of R)
+        let vectors = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac",
+            ),
+        ];
+
+        for (secret_key, msg, R_bytes) in vectors.iter() {
+            let h = sha2::Sha512::digest(&hex::decode(secret_key).unwrap());
+            let prefix = &h[32..];
+
+            let r = Scalar::from_hash_prefixed::<sha2::Sha512>(prefix, &hex::decode(msg).unwrap());
+
+            let R = &constants::ED25519_BASEPOINT_TABLE * &r;
+            assert_eq!(R.compress().as_bytes()[..], hex::decode(R_bytes).unwrap()[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unstable_montgomery_limbs")]
+    fn montgomery_limbs_roundtrip() {
+        for s in [X, XINV, Y, Scalar::zero(), Scalar::one(), -Scalar::one()].iter() {
+            let limbs = s.to_montgomery_limbs();
+            assert_eq!(Scalar::from_montgomery_limbs(limbs).unwrap(), *s);
+        }
+
+        // One in Montgomery form is R mod l
+        assert_eq!(Scalar::one().to_montgomery_limbs(), constants::R.0);
+        assert_eq!(Scalar::from_montgomery_limbs(constants::R.0).unwrap(), Scalar::one());
+
+        // l itself and an out-of-range limb are rejected
+        let mut limbs = constants::L.0;
+        assert!(bool::from(Scalar::from_montgomery_limbs(limbs).is_none()));
+        limbs = Scalar::one().to_montgomery_limbs();
+        limbs[0] = !0;
+        assert!(bool::from(Scalar::from_montgomery_limbs(limbs).is_none()));
+    }
+
+    #[test]
+    fn ct_fits_in_bits() {
+        for &n in [1u32, 7, 8, 9, 63, 64, 100, 200, 251].iter() {
+            let two_to_the_n = (0..n).fold(Scalar::one(), |acc, _| acc + acc);
+            assert!(bool::from((two_to_the_n - Scalar::one()).ct_fits_in_bits(n)));
+            assert!(!bool::from(two_to_the_n.ct_fits_in_bits(n)));
+            assert!(!bool::from((two_to_the_n + Scalar::one()).ct_fits_in_bits(n)));
+            assert!(bool::from(two_to_the_n.ct_fits_in_bits(n + 1)));
+        }
+
+        assert!(bool::from(Scalar::zero().ct_fits_in_bits(0)));
+        assert!(!bool::from(Scalar::one().ct_fits_in_bits(0)));
+
+        // Every canonical scalar is less than 2^253
+        assert!(bool::from((-Scalar::one()).ct_fits_in_bits(253)));
+        assert!(!bool::from((-Scalar::one()).ct_fits_in_bits(252)));
+        assert!(bool::from((-Scalar::one()).ct_fits_in_bits(1000)));
+
+        // The value is taken modulo l
+        assert!(bool::from(constants::BASEPOINT_ORDER.ct_fits_in_bits(0)));
+    }
+
     #[test]
     fn from_bytes_mod_order_wide() {
         let mut bignum = [0u8; 64];
@@ -1590,6 +2637,221 @@ mod test {
         assert_eq!(should_be_one, Scalar::one());
     }
 
+    #[test]
+    fn invert_fermat() {
+        assert_eq!(X.invert_fermat(), XINV);
+        assert_eq!(XINV.invert_fermat(), X);
+        assert_eq!(Scalar::one().invert_fermat(), Scalar::one());
+        assert_eq!(Scalar::zero().invert_fermat(), Scalar::zero());
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let a = Scalar::random(&mut rng);
+            assert_eq!(a.invert_fermat(), a.invert());
+            assert_eq!(a * a.invert_fermat(), Scalar::one());
+        }
+    }
+
+    #[test]
+    fn equals_u64() {
+        assert!(Scalar::zero().equals_u64(0));
+        assert!(!Scalar::zero().equals_u64(1));
+        assert!(Scalar::one().equals_u64(1));
+        assert!(!Scalar::one().equals_u64(0));
+        assert!(Scalar::from(u64::max_value()).equals_u64(u64::max_value()));
+        assert!(!Scalar::from(u64::max_value()).equals_u64(u64::max_value() - 1));
+
+        // 2^64 does not fit in a u64
+        let two_64 = Scalar::from(u64::max_value()) + Scalar::one();
+        assert!(!two_64.equals_u64(0));
+
+        // The unreduced scalar l + 1 has canonical value 1
+        let mut unreduced = constants::BASEPOINT_ORDER.bytes;
+        unreduced[0] += 1;
+        assert!(Scalar::from_bits(unreduced).equals_u64(1));
+        assert!(!Scalar::from_bits(unreduced).equals_u64(0));
+    }
+
+    #[test]
+    fn lagrange_coefficients_reconstruct_secret() {
+        let mut rng = rand::thread_rng();
+        // f(x) = secret + a_1 x + a_2 x^2, so any three shares suffice.
+        let secret = Scalar::random(&mut rng);
+        let (a_1, a_2) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+        let f = |x: u64| {
+            let x = Scalar::from(x);
+            secret + a_1 * x + a_2 * x * x
+        };
+
+        for indices in [[1u64, 2, 3], [7, 3, 12], [2, 1000, u64::max_value()]].iter() {
+            let lambdas = Scalar::lagrange_coefficients(indices);
+            let reconstructed: Scalar = lambdas
+                .iter()
+                .zip(indices.iter())
+                .map(|(l, &x)| l * f(x))
+                .sum();
+            assert_eq!(reconstructed, secret);
+        }
+
+        // With more shares than needed, the extra coefficient is not zero,
+        // but the reconstruction still holds.
+        let indices = [1u64, 4, 5, 9];
+        let lambdas = Scalar::lagrange_coefficients(&indices);
+        let reconstructed: Scalar = lambdas.iter().zip(indices.iter()).map(|(l, &x)| l * f(x)).sum();
+        assert_eq!(reconstructed, secret);
+        assert_eq!(lambdas.iter().sum::<Scalar>(), Scalar::one());
+
+        // The share at x = 0 is the secret itself.
+        assert_eq!(
+            Scalar::lagrange_coefficients(&[0, 5]),
+            vec![Scalar::one(), Scalar::zero()]
+        );
+        assert_eq!(Scalar::lagrange_coefficients(&[3]), vec![Scalar::one()]);
+        assert!(Scalar::lagrange_coefficients(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn lagrange_coefficients_reject_duplicates() {
+        Scalar::lagrange_coefficients(&[1, 2, 1]);
+    }
+
+    #[test]
+    fn wide_big_endian_reduction() {
+        let mut wide = [0u8; 64];
+        for (i, b) in wide.iter_mut().enumerate() {
+            *b = i as u8 + 1;
+        }
+        assert_eq!(
+            hex::encode(Scalar::from_bytes_mod_order_wide_be(&wide).as_bytes()),
+            "d5f42b070769c017539b6cd7ca15c8efe7466477a29f106e9a04093eefbbc900"
+        );
+        let mut wide_le = wide;
+        wide_le.reverse();
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide_be(&wide),
+            Scalar::from_bytes_mod_order_wide(&wide_le)
+        );
+
+        let mut narrow = [0u8; 48];
+        narrow.copy_from_slice(&wide[..48]);
+        assert_eq!(
+            hex::encode(Scalar::from_bytes_mod_order_384_be(&narrow).as_bytes()),
+            "30124cc19e6413399ae231a092335785cb57a595b6317b9e407dece1a783c10f"
+        );
+    }
+
+    #[test]
+    fn big_endian_bytes_roundtrip() {
+        let mut x_be = X.to_bytes();
+        x_be.reverse();
+        assert_eq!(X.to_bytes_be(), x_be);
+        assert_eq!(Scalar::from_bytes_mod_order_be(X.to_bytes_be()), X);
+
+        // Unreduced big-endian input is reduced like the little-endian one
+        let mut unreduced_be = [0xffu8; 32];
+        unreduced_be[0] = 0x7f;
+        let mut unreduced_le = unreduced_be;
+        unreduced_le.reverse();
+        assert_eq!(
+            Scalar::from_bytes_mod_order_be(unreduced_be),
+            Scalar::from_bytes_mod_order(unreduced_le)
+        );
+    }
+
+    #[test]
+    fn derive_from_seed_vectors() {
+        let seed = [0x42u8; 32];
+        let derived: Vec<_> = (0..3u64)
+            .map(|i| hex::encode(Scalar::derive_from_seed::<sha2::Sha512>(&seed, i).as_bytes()))
+            .collect();
+        // These are fixed, so every backend must reproduce them.
+        assert_eq!(
+            derived,
+            [
+                "aacc8595df8bd14b7b59ee201af45d9469a3a9e754640f3e4db4f229da3c2405",
+                "3128ffa2c1fe9ad6be31ec43aa75240bf477ea95fd824198ea110655153e3b09",
+                "604b8dd1cd0740ac179483ac7106b7a2ac3a13528f73d471ec8439141cf64904",
+            ]
+        );
+
+        let mut input = seed.to_vec();
+        input.extend_from_slice(&[0xff; 8]);
+        assert_eq!(
+            Scalar::derive_from_seed::<sha2::Sha512>(&seed, u64::max_value()),
+            Scalar::hash_from_bytes::<sha2::Sha512>(&input)
+        );
+    }
+
+    #[test]
+    fn geometric_sum() {
+        let mut rng = rand::thread_rng();
+        let xs = [
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            Scalar::from(2u64),
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ];
+
+        for x in xs.iter() {
+            let mut naive = Scalar::zero();
+            let mut x_i = Scalar::one();
+            for n in 0..40u64 {
+                assert_eq!(x.geometric_sum(n), naive, "x = {:?}, n = {}", x, n);
+                naive += x_i;
+                x_i *= x;
+            }
+        }
+
+        assert_eq!(Scalar::one().geometric_sum(u64::max_value()), Scalar::from(u64::max_value()));
+    }
+
+    #[test]
+    fn neg_invert() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let r = Scalar::random(&mut rng);
+            assert_eq!(r.neg_invert().unwrap() * r, -Scalar::one());
+        }
+
+        assert!(bool::from(Scalar::zero().neg_invert().is_none()));
+        // An unreduced encoding of zero
+        let l = Scalar { bytes: constants::BASEPOINT_ORDER.bytes };
+        assert!(bool::from(l.neg_invert().is_none()));
+    }
+
+    #[test]
+    fn sqrt() {
+        // A square root of -1 mod l
+        let sqrt_minus_one = Scalar::from_canonical_bytes([
+            0xd4, 0x07, 0xbe, 0xeb, 0xdf, 0x75, 0x87, 0xbe,
+            0xfe, 0x83, 0xce, 0x42, 0x53, 0x56, 0xf0, 0x0e,
+            0x7a, 0xc2, 0xc1, 0xab, 0x60, 0x6d, 0x3d, 0x7d,
+            0xe7, 0x81, 0x79, 0xe0, 0x10, 0x73, 0x4a, 0x09,
+        ]).unwrap();
+        let minus_one = -Scalar::one();
+        let r = minus_one.sqrt().unwrap();
+        assert!(r == sqrt_minus_one || r == -sqrt_minus_one);
+        assert_eq!(r * r, minus_one);
+
+        assert_eq!(Scalar::zero().sqrt().unwrap(), Scalar::zero());
+        assert_eq!(Scalar::one().sqrt().unwrap() * Scalar::one().sqrt().unwrap(), Scalar::one());
+
+        // 2 is a quadratic nonresidue mod l, hence so is 2 * r^2 for any nonzero r
+        assert!(bool::from(Scalar::from(2u64).sqrt().is_none()));
+        let two_x_sq = Scalar::from(2u64) * X * X;
+        assert!(bool::from(two_x_sq.sqrt().is_none()));
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..32 {
+            let x = Scalar::random(&mut rng);
+            let r = (x * x).sqrt().unwrap();
+            assert!(r == x || r == -x);
+        }
+    }
+
     // Negating a scalar twice should result in the original scalar.
     #[allow(non_snake_case)]
     #[test]
@@ -1644,6 +2906,87 @@ mod test {
         assert_eq!(montgomery_reduced.0, expected.unpack().0)
     }
 
+    #[test]
+    fn mul_wide_accumulate_then_reduce() {
+        let mut rng = rand::thread_rng();
+
+        // The maximal number of products of canonical scalars
+        let a: Vec<Scalar> = (0..64).map(|_| Scalar::random(&mut rng)).collect();
+        let b: Vec<Scalar> = (0..64).map(|_| Scalar::random(&mut rng)).collect();
+        let mut acc = UnreducedScalar::zero();
+        let mut expected = Scalar::zero();
+        for (a_i, b_i) in a.iter().zip(b.iter()) {
+            acc += a_i.mul_wide(b_i);
+            expected += a_i * b_i;
+        }
+        assert_eq!(acc.reduce(), expected);
+
+        // The maximal number of products of unreduced scalars
+        let max = Scalar::from_bits([0xff; 32]);
+        let acc = (0..4).fold(UnreducedScalar::zero(), |acc, _| acc + max.mul_wide(&max));
+        assert_eq!(acc.reduce(), Scalar::from(4u64) * max.reduce() * max.reduce());
+
+        // Small products are exact
+        let p = Scalar::from(u64::max_value()).mul_wide(&Scalar::from(u64::max_value()));
+        let mut p_bytes = [0u8; 64];
+        p_bytes[..16].copy_from_slice(&((u64::max_value() as u128) * (u64::max_value() as u128)).to_le_bytes());
+        assert_eq!(p.to_bytes()[..], p_bytes[..]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn unreduced_scalar_addition_overflow_panics_in_debug_builds() {
+        let max = Scalar::from_bits([0xff; 32]);
+        let big = (0..4).fold(UnreducedScalar::zero(), |acc, _| acc + max.mul_wide(&max));
+        let _ = big + big + big + big + big;
+    }
+
+    #[test]
+    fn ordering_matches_integer_values() {
+        let mut scalars: Vec<Scalar> = [5u64, 256, 1, 0, 255, 1 << 40]
+            .iter()
+            .map(|&x| Scalar::from(x))
+            .collect();
+        scalars.push(-Scalar::one());
+        scalars.sort();
+
+        let expected: Vec<Scalar> = [0u64, 1, 5, 255, 256, 1 << 40]
+            .iter()
+            .map(|&x| Scalar::from(x))
+            .chain(::core::iter::once(-Scalar::one()))
+            .collect();
+        assert_eq!(scalars, expected);
+
+        // An unreduced scalar is ordered by its unreduced value
+        let unreduced = Scalar::from_bits([0xff; 32]);
+        assert!(unreduced > -Scalar::one());
+        assert!(unreduced.reduce() < unreduced);
+        assert_eq!(X.cmp(&X), Ordering::Equal);
+    }
+
+    #[test]
+    fn from_integers_ct_matches_from() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        let mut u64s = vec![0u64, 1, u64::max_value()];
+        let mut u128s = vec![0u128, 1, u64::max_value() as u128 + 1, u128::max_value()];
+        for _ in 0..16 {
+            u64s.push(rng.gen());
+            u128s.push(rng.gen());
+        }
+
+        for &x in u64s.iter() {
+            assert_eq!(Scalar::from_u64_ct(x), Scalar::from(x));
+            assert!(Scalar::from_u64_ct(x).is_canonical());
+        }
+        for &x in u128s.iter() {
+            assert_eq!(Scalar::from_u128_ct(x), Scalar::from(x));
+            assert!(Scalar::from_u128_ct(x).is_canonical());
+        }
+    }
+
     #[test]
     fn canonical_decoding() {
         // canonical encoding of 1667457891
@@ -1663,6 +3006,32 @@ mod test {
         assert!( Scalar::from_canonical_bytes(non_canonical_bytes_because_highbit).is_none() );
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn read_from_write_to() {
+        use std::io::ErrorKind;
+
+        let mut buf = Vec::new();
+        X.write_to(&mut buf).unwrap();
+        Y.write_to(&mut buf).unwrap();
+        assert_eq!(buf.len(), 64);
+
+        let mut reader = &buf[..];
+        assert_eq!(Scalar::read_from(&mut reader, true).unwrap(), X);
+        assert_eq!(Scalar::read_from(&mut reader, true).unwrap(), Y);
+        let err = Scalar::read_from(&mut reader, true).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        // [16; 32] is not reduced mod l
+        let unreduced = [16u8; 32];
+        let err = Scalar::read_from(&mut &unreduced[..], true).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            Scalar::read_from(&mut &unreduced[..], false).unwrap(),
+            Scalar::from_bytes_mod_order(unreduced)
+        );
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde_bincode_scalar_roundtrip() {
@@ -1681,14 +3050,35 @@ mod test {
         );
     }
 
-    #[cfg(debug_assertions)]
     #[test]
-    #[should_panic]
-    fn batch_invert_with_a_zero_input_panics() {
-        let mut xs = vec![Scalar::one(); 16];
-        xs[3] = Scalar::zero();
-        // This should panic in debug mode.
-        Scalar::batch_invert(&mut xs);
+    fn batch_invert_with_zero_inputs() {
+        let mut x = Scalar::from(3u64);
+        let mut xs: Vec<_> = (0..16).map(|i| {
+            let tmp = x;
+            x = x + x;
+            if i % 3 == 0 { Scalar::zero() } else { tmp }
+        }).collect();
+        let original = xs.clone();
+
+        let expected: Scalar = original
+            .iter()
+            .filter(|x| **x != Scalar::zero())
+            .product();
+        let ret = Scalar::batch_invert(&mut xs);
+        assert_eq!(ret, expected.invert());
+
+        for (inv, x) in xs.iter().zip(original.iter()) {
+            if *x == Scalar::zero() {
+                assert_eq!(*inv, Scalar::zero());
+            } else {
+                assert_eq!(inv * x, Scalar::one());
+            }
+        }
+
+        // All-zero input is left untouched, and the empty product is returned
+        let mut zeros = vec![Scalar::zero(); 4];
+        assert_eq!(Scalar::batch_invert(&mut zeros), Scalar::one());
+        assert!(zeros.iter().all(|x| *x == Scalar::zero()));
     }
 
     #[test]
@@ -1751,4 +3141,56 @@ mod test {
             test_pippenger_radix_iter(scalar, 8);
         }
     }
+
+    #[test]
+    fn varint_roundtrip() {
+        let mut two_224 = [0u8; 32];
+        two_224[28] = 1;
+        let two_224 = Scalar::from_canonical_bytes(two_224).unwrap();
+
+        let cases = [
+            (Scalar::zero(), 1),
+            (Scalar::one(), 1),
+            (Scalar::from(127u64), 1),
+            (Scalar::from(128u64), 2),
+            (Scalar::from(u64::max_value()), 10),
+            (two_224 - Scalar::one(), 32),
+            (two_224, 33),
+            (-Scalar::one(), 33),
+        ];
+        for (s, len) in cases.iter() {
+            let encoded = s.to_varint();
+            assert_eq!(encoded.len(), *len);
+            assert_eq!(Scalar::from_varint(&encoded), Some(*s));
+        }
+        assert_eq!(Scalar::from(624485u64).to_varint(), vec![0xe5, 0x8e, 0x26]);
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(Scalar::from_varint(&s.to_varint()), Some(s));
+        }
+    }
+
+    #[test]
+    fn varint_rejects_noncanonical() {
+        assert_eq!(Scalar::from_varint(&[]), None);
+        // Missing final byte, and a trailing zero group
+        assert_eq!(Scalar::from_varint(&[0x85]), None);
+        assert_eq!(Scalar::from_varint(&[0x85, 0x00]), None);
+        // 33 bytes of LEB128
+        let mut long = [0x80u8; 33];
+        long[32] = 0x01;
+        assert_eq!(Scalar::from_varint(&long), None);
+
+        // The flagged form, for a small scalar and for an unreduced one
+        let mut flagged = [0u8; 33];
+        flagged[0] = 0xff;
+        flagged[1] = 5;
+        assert_eq!(Scalar::from_varint(&flagged), None);
+        for b in flagged[1..].iter_mut() {
+            *b = 0xff;
+        }
+        assert_eq!(Scalar::from_varint(&flagged), None);
+    }
 }
@@ -42,6 +42,7 @@ macro_rules! impl_lookup_table {
 /// still technically possible.  It would be nice to prevent direct
 /// access to the table.
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "ffi", repr(transparent))]
 pub struct $name<T>(pub(crate) [T; $size]);
 
 impl<T> $name<T>